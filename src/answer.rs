@@ -0,0 +1,191 @@
+/*
+ * Answers returned by solutions and the store of accepted answers.
+ */
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+/// The answer to a puzzle part.
+/// `Grid` holds multiline answers such as letters drawn on a screen, one entry per row.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    /// A grid answer drawn with `#` for lit and `.` for dark pixels.
+    pub fn from_pixels(pixels: &[Vec<bool>]) -> Self {
+        Answer::Grid(
+            pixels
+                .iter()
+                .map(|row| row.iter().map(|lit| if *lit { '#' } else { '.' }).collect())
+                .collect(),
+        )
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Grid(_))
+    }
+
+    /// Renders the answer for terminal output. Multiline answers become an indented block.
+    pub fn to_block(&self) -> String {
+        match self {
+            Answer::Grid(rows) => rows
+                .iter()
+                .map(|row| format!("    {}", row))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Answer {
+                fn from(value: $ty) -> Self {
+                    // integers that do not fit an `i64` are kept exactly as text.
+                    i64::try_from(value).map_or_else(|_| Answer::Text(value.to_string()), Answer::Integer)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(rows: Vec<String>) -> Self {
+        Answer::Grid(rows)
+    }
+}
+
+/// Accepted answers per day and part, stored as JSON in `src/answers.json`.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    path: PathBuf,
+    days: BTreeMap<u8, BTreeMap<u8, Answer>>,
+}
+
+impl AnswerStore {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from("src").join("answers.json")
+    }
+
+    /// Loads the store, starting with an empty one if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let days = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(AnswerStore {
+            path: path.to_path_buf(),
+            days,
+        })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.days)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, json + "\n")
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.days.get(&day).and_then(|parts| parts.get(&part))
+    }
+
+    pub fn accept(&mut self, day: u8, part: u8, answer: Answer) {
+        self.days.entry(day).or_default().insert(part, answer);
+    }
+
+    /// Compares an answer against the accepted one. `None` if no answer was accepted yet.
+    pub fn verify(&self, day: u8, part: u8, answer: &Answer) -> Option<bool> {
+        self.get(day, part).map(|accepted| accepted == answer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Answer::from(42_u32), Answer::Integer(42));
+        assert_eq!(Answer::from(-3_i32), Answer::Integer(-3));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_string())
+        );
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_string()));
+        assert_eq!(
+            Answer::from_pixels(&[vec![true, false], vec![false, true]]),
+            Answer::Grid(vec!["#.".to_string(), ".#".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_to_block() {
+        assert_eq!(Answer::Integer(7).to_block(), "7");
+        let grid = Answer::Grid(vec!["#.".to_string(), ".#".to_string()]);
+        assert_eq!(grid.to_block(), "    #.\n    .#");
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = vec![
+            Answer::Integer(24000),
+            Answer::Text("CMZ".to_string()),
+            Answer::Grid(vec!["#..#".to_string(), "####".to_string()]),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, "[24000,\"CMZ\",[\"#..#\",\"####\"]]");
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_store_verify() {
+        let mut store = AnswerStore::default();
+        let grid = Answer::Grid(vec!["#.".to_string()]);
+        store.accept(10, 2, grid.clone());
+
+        assert_eq!(store.verify(10, 2, &grid), Some(true));
+        assert_eq!(
+            store.verify(10, 2, &Answer::Grid(vec!["#. ".to_string()])),
+            Some(false)
+        );
+        assert_eq!(store.verify(10, 1, &grid), None);
+    }
+}
@@ -1,151 +1,52 @@
-#[derive(Debug)]
-struct Stack {
-	cargos: Vec<char>,
-}
-
-impl Stack {
-	fn new() -> Self {
-		Stack { cargos: Vec::new() }
-	}
-
-	fn push(&mut self, c: char) {
-		self.cargos.push(c);
-	}
-
-	fn pop(&mut self) -> Option<char> {
-		self.cargos.pop()
-	}
-
-	fn peek(&self) -> Option<&char> {
-		self.cargos.last()
-	}
-
-	fn insert_bottom(&mut self, c: char) {
-		self.cargos.insert(0, c);
-	}
-
-	fn insert_at(&mut self, c: char, index: usize) {
-		self.cargos.insert(index, c);
-	}
-}
-
-#[derive(Debug)]
-struct Warehouse {
-	stack_count: u32,
-	stacks: Vec<Stack>,
-}
-
-fn get_input_stack_count(s: &str) -> u32 {
-	((s.lines().next().unwrap().chars().count() + 1) / 4) as u32
-}
-
-impl From<&str> for Warehouse {
-	fn from(s: &str) -> Self {
-		let stack_count = get_input_stack_count(s);
-		let mut stacks = Vec::new();
-		for _ in 0..stack_count {
-			stacks.push(Stack::new());
-		}
-
-		'outer: for line in s.lines() {
-			let mut chars = line.chars();
-			for i in 0..stack_count {
-				chars.next();
-
-				let relevant = chars.next().unwrap();
-				if relevant != ' ' {
-					if let Some(_) = relevant.to_digit(10) {
-						break 'outer;
-					}
-					stacks[i as usize].insert_bottom(relevant);
-				}
-
-				chars.next();
-				chars.next();
-			}
-			
-		}
-		Warehouse { stack_count, stacks }
-	}
-}
-
-impl Warehouse {
-	pub fn apply_str_operations(&mut self, operations: &str, mover: fn(&mut Warehouse, count: u32, source: usize, destination: usize)) {
-		for operation in operations.lines() {
-			let mut operation_parts = operation.split_whitespace();
-			operation_parts.next();
-			let count = operation_parts.next().unwrap().parse::<u32>().unwrap();
-			operation_parts.next();
-			let source = operation_parts.next().unwrap().parse::<u32>().unwrap() - 1;
-			operation_parts.next();
-			let destination = operation_parts.next().unwrap().parse::<u32>().unwrap() - 1;
-			mover(self, count, source as usize, destination as usize);
-		}
-	}
-
-	fn crate_mover_9000(&mut self, count: u32, source: usize, destination: usize) {
-		for _ in 0..count {
-			let c = self.stacks[source].pop().unwrap();
-			self.stacks[destination].push(c);
-		}
-	}
-
-	fn crate_mover_9001(&mut self, count: u32, source: usize, destination: usize) {
-		let index = self.stacks[destination].cargos.len();
-		for _ in 0..count {
-			let c = self.stacks[source].pop().unwrap();
-			self.stacks[destination].insert_at(c, index);
-		}
-	}
-
-	fn get_top_cargos(&self) -> Vec<char> {
-		let mut cargos = Vec::new();
-		for stack in &self.stacks {
-			cargos.push(*stack.peek().unwrap());
-		}
-		cargos
-	}
-}
-
-fn get_movement_result(input: &str, mover: fn(&mut Warehouse, count: u32, source: usize, destination: usize)) -> Option<String> {
-	let mut split = input.split("\n\n");
-    let stack_input = split.next().unwrap();
-
-	let mut warehouse = Warehouse::from(stack_input);
-
-	let command_input = split.next().unwrap();
-	warehouse.apply_str_operations(command_input, mover);
-
-	Some(warehouse.get_top_cargos().iter().collect())
-}
-
-pub fn part_one(input: &str) -> Option<String> {
-	get_movement_result(input, Warehouse::crate_mover_9000)
-}
-
-pub fn part_two(input: &str) -> Option<String> {
-    get_movement_result(input, Warehouse::crate_mover_9001)
-}
+use advent_of_code::days::day05::*;
+use advent_of_code::helpers::generate;
+use advent_of_code::{runner, scaling};
 
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
+    if generate::print_if_requested(generate) {
+        return;
+    }
+    if scaling::run_if_requested(generate, part_one, part_two) {
+        return;
+    }
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_string()));
+    let mut args = pico_args::Arguments::from_env();
+    let benchmark = args.contains("--benchmark");
+    let input = &if benchmark {
+        let moves = args.opt_value_from_str("--moves").unwrap().unwrap_or(100_000);
+        let height = args.opt_value_from_str("--height").unwrap().unwrap_or(1_000);
+        println!("Benchmarking {} moves on stacks of up to {} crates.", moves, height);
+        generate_warehouse(&mut generate::Rng::new(0), moves, height)
+    } else {
+        advent_of_code::read_file("inputs", 5)
+    };
+    if args.contains("--reverse") {
+        let part: u8 = args.opt_value_from_str("--part").unwrap().unwrap_or(1);
+        let mover: &dyn CrateMover = if part == 2 { &CrateMover9001 } else { &CrateMover9000 };
+        let (last, operations) = parse_input(input).unwrap();
+        match reverse_solve(&last, operations, mover) {
+            Ok(first) => println!("{}", first),
+            Err(e) => eprintln!("The drawing cannot be the result of the moves: {}", e),
+        }
+        // the drawing is not a valid starting point for the parts.
+        return;
+    }
+    if benchmark {
+        // the generated warehouse has no accepted answer to check or overwrite.
+        runner::run_unchecked_variants(5, 1, PART_ONE_VARIANTS, input);
+        runner::run_unchecked_variants(5, 2, PART_TWO_VARIANTS, input);
+    } else {
+        advent_of_code::solve!(1, variants: PART_ONE_VARIANTS, input);
+        advent_of_code::solve!(2, variants: PART_TWO_VARIANTS, input);
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_string()));
+    if args.contains("--replay") {
+        let part: u8 = args.opt_value_from_str("--part").unwrap().unwrap_or(1);
+        let mover: &dyn CrateMover = if part == 2 { &CrateMover9001 } else { &CrateMover9000 };
+        let (mut warehouse, operations) = parse_input(input).unwrap();
+        println!("\n{}", warehouse);
+        warehouse
+            .replay(operations, mover, |operation, warehouse| println!("\n{}\n{}", operation, warehouse))
+            .unwrap();
     }
 }
@@ -0,0 +1,41 @@
+/*
+ * An interactive overview of all days, see `advent_of_code::dashboard`.
+ */
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use advent_of_code::dashboard::{Action, Dashboard};
+
+fn main() {
+    let mut dashboard = match Dashboard::load() {
+        Ok(dashboard) => dashboard,
+        Err(e) => {
+            eprintln!("Failed to load answers or history: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}", dashboard.render());
+        io::stdout().flush().unwrap();
+
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match dashboard.handle(&line) {
+            Ok(Action::Render) => {}
+            Ok(Action::Pause) => {
+                print!("Press enter to return to the dashboard.");
+                io::stdout().flush().unwrap();
+                lines.next();
+            }
+            Ok(Action::Quit) => break,
+            Err(e) => {
+                eprintln!("Command failed: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * Manages encrypted inputs, see `advent_of_code::encryption`.
+ * - `cargo inputs keygen` creates a new key file.
+ * - `cargo inputs encrypt [day]` writes `src/inputs/{day}.txt.enc` for one or all plaintext inputs.
+ * - `cargo inputs decrypt [day]` restores missing plaintext inputs from their encrypted files.
+ */
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+
+use advent_of_code::encryption::{self, Key};
+
+fn input_path(day: u8) -> PathBuf {
+    PathBuf::from("src")
+        .join("inputs")
+        .join(format!("{:02}.txt", day))
+}
+
+fn load_key() -> Key {
+    match Key::from_env() {
+        Ok(Some(key)) => key,
+        Ok(None) => {
+            eprintln!("{}", encryption::EncryptionError::MissingKey);
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to load key: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+fn keygen() {
+    let path = encryption::key_file_path();
+    let key = Key::generate();
+    let written = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{}", key.to_hex()));
+    match written {
+        Ok(_) => println!(
+            "Created key file {:?}. Share it privately, e.g. as the `{}` secret in CI.",
+            path,
+            encryption::KEY_ENV
+        ),
+        Err(e) => {
+            eprintln!("Failed to create key file {:?}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn encrypt(key: &Key, day: u8) {
+    let path = input_path(day);
+    let Some(input) = fs::read_to_string(&path)
+        .ok()
+        .filter(|input| !input.trim().is_empty())
+    else {
+        return;
+    };
+
+    let encrypted_path = encryption::encrypted_path(&path);
+    match fs::write(&encrypted_path, encryption::encrypt(key, day, &input)) {
+        Ok(_) => println!("Encrypted {:?}", encrypted_path),
+        Err(e) => {
+            eprintln!("Failed to write {:?}: {}", encrypted_path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn decrypt(day: u8) {
+    let path = input_path(day);
+    if fs::read_to_string(&path).is_ok_and(|input| !input.trim().is_empty()) {
+        println!("Skipped {:?}, it already exists.", path);
+        return;
+    }
+
+    match encryption::read_encrypted(&path, day) {
+        Ok(Some(input)) => match fs::write(&path, input) {
+            Ok(_) => println!("Decrypted {:?}", path),
+            Err(e) => {
+                eprintln!("Failed to write {:?}: {}", path, e);
+                process::exit(1);
+            }
+        },
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to decrypt {:?}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let command: Option<String> = args.subcommand().unwrap_or(None);
+    let days: Vec<u8> = match args.opt_free_from_str::<u8>() {
+        Ok(Some(day)) => vec![day],
+        Ok(None) => (1..=25).collect(),
+        Err(_) => {
+            eprintln!("The day must be a number. example: `cargo inputs encrypt 7`");
+            process::exit(1);
+        }
+    };
+
+    match command.as_deref() {
+        Some("keygen") => keygen(),
+        Some("encrypt") => {
+            let key = load_key();
+            days.into_iter().for_each(|day| encrypt(&key, day));
+        }
+        Some("decrypt") => days.into_iter().for_each(decrypt),
+        _ => {
+            eprintln!("Need a command: `cargo inputs <keygen|encrypt|decrypt> [day]`");
+            process::exit(1);
+        }
+    }
+}
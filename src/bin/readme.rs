@@ -0,0 +1,68 @@
+/*
+ * Rewrites the progress section of `README.md`, see `advent_of_code::progress`.
+ * Pass `--run` to run all registered days on their inputs first, so the benchmarks are current.
+ */
+use std::fs;
+use std::process;
+
+use advent_of_code::answer::AnswerStore;
+use advent_of_code::days;
+use advent_of_code::history::{History, Run};
+use advent_of_code::progress;
+
+const README_PATH: &str = "README.md";
+
+fn main() {
+    let mut args = pico_args::Arguments::from_env();
+    let run = args.contains("--run");
+
+    let answers = AnswerStore::load(&AnswerStore::default_path()).unwrap_or_else(|e| {
+        eprintln!("Failed to load answers: {}", e);
+        process::exit(1);
+    });
+    let mut history = History::load(&History::default_path()).unwrap_or_else(|e| {
+        eprintln!("Failed to load history: {}", e);
+        process::exit(1);
+    });
+
+    if run {
+        for entry in days::DAYS {
+            let Some(input) = advent_of_code::try_read_file("inputs", entry.day) else {
+                continue;
+            };
+            for part in 1..=2 {
+                match days::run_part(entry.part(part).unwrap(), &input) {
+                    Ok((answer, elapsed)) => {
+                        history.record(entry.day, part, Run::new(answer, elapsed))
+                    }
+                    Err(e) => eprintln!("Day {:02} part {} failed: {}", entry.day, part, e),
+                }
+            }
+        }
+        if let Err(e) = history.save() {
+            eprintln!("Failed to save history: {}", e);
+            process::exit(1);
+        }
+    }
+
+    let readme = fs::read_to_string(README_PATH).unwrap_or_else(|e| {
+        eprintln!("Failed to read {}: {}", README_PATH, e);
+        process::exit(1);
+    });
+    let updated = match progress::replace_section(&readme, &progress::render(&answers, &history)) {
+        Ok(updated) => updated,
+        Err(e) => {
+            eprintln!("Failed to update {}: {}", README_PATH, e);
+            process::exit(1);
+        }
+    };
+
+    if updated == readme {
+        println!("{} is up to date.", README_PATH);
+    } else if let Err(e) = fs::write(README_PATH, updated) {
+        eprintln!("Failed to write {}: {}", README_PATH, e);
+        process::exit(1);
+    } else {
+        println!("Updated the progress section of {}.", README_PATH);
+    }
+}
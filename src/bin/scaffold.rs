@@ -3,7 +3,7 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
@@ -16,30 +16,42 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    crate::test_day! {
+        day: DAY,
+        example: (None, None),
     }
+}
+"###;
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
-    }
+const BIN_TEMPLATE: &str = r###"use advent_of_code::days::dayPADDED::*;
+
+fn main() {
+    let input = &advent_of_code::read_file("inputs", DAY);
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
 }
 "###;
 
+const DAYS_PATH: &str = "src/days.rs";
+
+/// Declares the new module after the existing ones in `src/days.rs`.
+/// Add the day to the `DAYS` registry there to make it available to tools like `cargo serve`.
+fn register_module(day_padded: &str) -> Result<(), std::io::Error> {
+    let days = fs::read_to_string(DAYS_PATH)?;
+    let mut lines: Vec<&str> = days.lines().collect();
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod day"))
+        .map_or(lines.len(), |index| index + 1);
+    let declaration = format!("pub mod day{};", day_padded);
+    lines.insert(position, &declaration);
+    fs::write(DAYS_PATH, lines.join("\n") + "\n")
+}
+
 fn parse_args() -> Result<u8, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     args.free_from_str()
@@ -50,7 +62,11 @@ fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
@@ -66,7 +82,8 @@ fn main() {
 
     let input_path = format!("src/inputs/{}.txt", day_padded);
     let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let module_path = format!("src/days/day{}.rs", day_padded);
+    let bin_path = format!("src/bin/{}.rs", day_padded);
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -86,6 +103,37 @@ fn main() {
         }
     }
 
+    let mut file = match safe_create_file(&bin_path) {
+        Ok(file) => file,
+        Err(e) => {
+            eprintln!("Failed to create binary file: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let bin = BIN_TEMPLATE
+        .replace("PADDED", &day_padded)
+        .replace("DAY", &day.to_string());
+    match file.write_all(bin.as_bytes()) {
+        Ok(_) => {
+            println!("Created binary file \"{}\"", &bin_path);
+        }
+        Err(e) => {
+            eprintln!("Failed to write binary contents: {}", e);
+            process::exit(1);
+        }
+    }
+
+    match register_module(&day_padded) {
+        Ok(_) => {
+            println!("Registered module in \"{}\"", DAYS_PATH);
+        }
+        Err(e) => {
+            eprintln!("Failed to register module: {}", e);
+            process::exit(1);
+        }
+    }
+
     match create_file(&input_path) {
         Ok(_) => {
             println!("Created empty input file \"{}\"", &input_path);
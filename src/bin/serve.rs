@@ -0,0 +1,24 @@
+/*
+ * Serves the solvers of all days as a local HTTP/JSON API, see `advent_of_code::service`.
+ */
+use std::process;
+
+use advent_of_code::service::{self, Options};
+
+fn main() {
+    let options = match Options::from_env() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!(
+                "Invalid arguments: {}. example: `cargo serve --port 3030 --timeout 10`",
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = service::serve(&options) {
+        eprintln!("Failed to serve: {}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,331 @@
+/*
+ * An interactive terminal dashboard: a calendar of all 25 days with the state of each part,
+ * the latest answers, their verification and timing, and regressions against the run history.
+ * Days are run in-process through the solver registry in `days::DAYS`.
+ * Start it with `cargo dashboard`.
+ */
+use std::collections::BTreeMap;
+use std::io;
+use std::process::Command;
+
+use crate::answer::{Answer, AnswerStore};
+use crate::days;
+use crate::history::{History, Regression, Run};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_REVERSE: &str = "\x1b[7m";
+const ANSI_DIM: &str = "\x1b[2m";
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
+const ANSI_YELLOW: &str = "\x1b[33m";
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+pub const HELP: &str =
+    "[1-25] select day  r re-run  a run all  t run tests  e show example  q quit";
+
+/// The state of a part, as shown in the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The day is not in the solver registry.
+    Unsolved,
+    NotRun,
+    Failed,
+    NoAnswer,
+    Unverified,
+    Verified,
+    Wrong,
+}
+
+impl Status {
+    fn symbol(&self) -> String {
+        match self {
+            Status::Unsolved => format!("{}-{}", ANSI_DIM, ANSI_RESET),
+            Status::NotRun => "○".to_string(),
+            Status::Failed => format!("{}!{}", ANSI_RED, ANSI_RESET),
+            Status::NoAnswer => "·".to_string(),
+            Status::Unverified => "?".to_string(),
+            Status::Verified => format!("{}★{}", ANSI_YELLOW, ANSI_RESET),
+            Status::Wrong => format!("{}✗{}", ANSI_RED, ANSI_RESET),
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Status::Unsolved => "not solved".to_string(),
+            Status::NotRun => "not run yet".to_string(),
+            Status::Failed => format!("{}failed{}", ANSI_RED, ANSI_RESET),
+            Status::NoAnswer => "no answer".to_string(),
+            Status::Unverified => "not verified".to_string(),
+            Status::Verified => format!("{}✓ verified{}", ANSI_GREEN, ANSI_RESET),
+            Status::Wrong => format!(
+                "{}✗ differs from the accepted answer{}",
+                ANSI_RED, ANSI_RESET
+            ),
+        }
+    }
+}
+
+/// What the input loop should do after a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Render,
+    /// Keep the output of the command on screen until the user continues.
+    Pause,
+    Quit,
+}
+
+pub struct Dashboard {
+    answers: AnswerStore,
+    history: History,
+    selected: u8,
+    /// Panic messages of parts that failed in this session.
+    errors: BTreeMap<(u8, u8), String>,
+    message: Option<String>,
+}
+
+impl Dashboard {
+    pub fn new(answers: AnswerStore, history: History) -> Self {
+        Dashboard {
+            answers,
+            history,
+            selected: 1,
+            errors: BTreeMap::new(),
+            message: None,
+        }
+    }
+
+    pub fn load() -> io::Result<Self> {
+        Ok(Dashboard::new(
+            AnswerStore::load(&AnswerStore::default_path())?,
+            History::load(&History::default_path())?,
+        ))
+    }
+
+    pub fn status(&self, day: u8, part: u8) -> Status {
+        if days::get(day).is_none() {
+            return Status::Unsolved;
+        }
+        if self.errors.contains_key(&(day, part)) {
+            return Status::Failed;
+        }
+        match self.history.latest(day, part) {
+            None => Status::NotRun,
+            Some(Run { answer: None, .. }) => Status::NoAnswer,
+            Some(Run {
+                answer: Some(answer),
+                ..
+            }) => match self.answers.verify(day, part, answer) {
+                None => Status::Unverified,
+                Some(true) => Status::Verified,
+                Some(false) => Status::Wrong,
+            },
+        }
+    }
+
+    /// Runs both parts of a day on its input and records them in the history.
+    pub fn run_day(&mut self, day: u8) -> io::Result<()> {
+        let Some(entry) = days::get(day) else {
+            self.message = Some(format!("Day {} is not solved yet.", day));
+            return Ok(());
+        };
+        let Some(input) = crate::try_read_file("inputs", day) else {
+            self.message = Some(format!("No input for day {}.", day));
+            return Ok(());
+        };
+
+        for (part, solver) in [(1, entry.part_one), (2, entry.part_two)] {
+            match days::run_part(solver, &input) {
+                Ok((answer, elapsed)) => {
+                    self.errors.remove(&(day, part));
+                    self.history.record(day, part, Run::new(answer, elapsed));
+                }
+                Err(error) => {
+                    self.errors.insert((day, part), error);
+                }
+            }
+        }
+        self.history.save()
+    }
+
+    /// Handles one line of input.
+    pub fn handle(&mut self, command: &str) -> io::Result<Action> {
+        self.message = None;
+        let command = command.trim();
+        if let Ok(day) = command.parse::<u8>() {
+            if (1..=25).contains(&day) {
+                self.selected = day;
+            } else {
+                self.message = Some("Days go from 1 to 25.".to_string());
+            }
+            return Ok(Action::Render);
+        }
+
+        match command {
+            "" => {}
+            "r" => self.run_day(self.selected)?,
+            "a" => {
+                for entry in days::DAYS {
+                    self.run_day(entry.day)?;
+                }
+            }
+            "t" => {
+                Command::new("cargo")
+                    .args(["test", "--lib", &format!("days::day{:02}::", self.selected)])
+                    .status()?;
+                return Ok(Action::Pause);
+            }
+            "e" => {
+                match crate::try_read_file("examples", self.selected) {
+                    Some(example) => println!("{}", example.trim_end()),
+                    None => println!("No example for day {}.", self.selected),
+                }
+                return Ok(Action::Pause);
+            }
+            "q" => return Ok(Action::Quit),
+            _ => self.message = Some(format!("Unknown command `{}`.", command)),
+        }
+        Ok(Action::Render)
+    }
+
+    pub fn render(&self) -> String {
+        let mut output = String::from(ANSI_CLEAR);
+        output += &format!("🎄 {}Advent of Code{} 🎄\n\n", ANSI_BOLD, ANSI_RESET);
+        for week in 0..5 {
+            for weekday in 1..=5 {
+                output += &self.render_cell(week * 5 + weekday);
+            }
+            output += "\n";
+        }
+        output += "\n";
+        output += &self.render_details(self.selected);
+
+        let stars = (1..=25)
+            .flat_map(|day| [(day, 1), (day, 2)])
+            .filter(|(day, part)| self.status(*day, *part) == Status::Verified)
+            .count();
+        output += &format!("\n{} ★ verified\n", stars);
+        if let Some(message) = &self.message {
+            output += &format!("{}\n", message);
+        }
+        output += &format!("{}{}{}\n> ", ANSI_ITALIC, HELP, ANSI_RESET);
+        output
+    }
+
+    fn render_cell(&self, day: u8) -> String {
+        let regressed = (1..=2).any(|part| self.history.regression(day, part).is_some());
+        let label = format!("{:02}", day);
+        let label = if day == self.selected {
+            format!("{}{}{}", ANSI_REVERSE, label, ANSI_RESET)
+        } else {
+            label
+        };
+        format!(
+            " {} {}{}{} ",
+            label,
+            self.status(day, 1).symbol(),
+            self.status(day, 2).symbol(),
+            if regressed {
+                format!("{}⚠{}", ANSI_RED, ANSI_RESET)
+            } else {
+                " ".to_string()
+            }
+        )
+    }
+
+    fn render_details(&self, day: u8) -> String {
+        let mut output = format!("{}Day {:02}{}\n", ANSI_BOLD, day, ANSI_RESET);
+        for part in 1..=2 {
+            let status = self.status(day, part);
+            output += &format!("  Part {}: {}", part, status.label());
+
+            if let Some(error) = self.errors.get(&(day, part)) {
+                output += &format!(" ({})\n", error);
+                continue;
+            }
+            let Some(latest) = self.history.latest(day, part) else {
+                output += "\n";
+                continue;
+            };
+            output += &format!(
+                " {}(elapsed: {:.2?}){}\n",
+                ANSI_ITALIC,
+                latest.elapsed(),
+                ANSI_RESET
+            );
+            if let Some(answer) = &latest.answer {
+                output += &format!("{}\n", indent(answer));
+            }
+
+            match self.history.regression(day, part) {
+                Some(Regression::AnswerChanged { previous }) => {
+                    output += &format!(
+                        "    {}⚠ the answer changed, it was {}{}\n",
+                        ANSI_RED,
+                        previous.map_or("none".to_string(), |answer| answer.to_string()),
+                        ANSI_RESET
+                    );
+                }
+                Some(Regression::Slower { ratio }) => {
+                    output += &format!(
+                        "    {}⚠ {:.1}x slower than the median of previous runs{}\n",
+                        ANSI_RED, ratio, ANSI_RESET
+                    );
+                }
+                None => {}
+            }
+        }
+        output
+    }
+}
+
+fn indent(answer: &Answer) -> String {
+    answer
+        .to_string()
+        .lines()
+        .map(|line| format!("    {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn dashboard() -> Dashboard {
+        Dashboard::new(AnswerStore::default(), History::default())
+    }
+
+    #[test]
+    fn test_status() {
+        let mut dashboard = dashboard();
+        assert_eq!(dashboard.status(25, 1), Status::Unsolved);
+        assert_eq!(dashboard.status(1, 1), Status::NotRun);
+
+        dashboard
+            .history
+            .record(1, 1, Run::new(Some(Answer::Integer(24000)), Duration::ZERO));
+        assert_eq!(dashboard.status(1, 1), Status::Unverified);
+
+        dashboard.answers.accept(1, 1, Answer::Integer(24000));
+        assert_eq!(dashboard.status(1, 1), Status::Verified);
+
+        dashboard.answers.accept(1, 1, Answer::Integer(1));
+        assert_eq!(dashboard.status(1, 1), Status::Wrong);
+    }
+
+    #[test]
+    fn test_commands() {
+        let mut dashboard = dashboard();
+        assert_eq!(dashboard.handle("7").unwrap(), Action::Render);
+        assert_eq!(dashboard.selected, 7);
+        assert!(dashboard.render().contains("Day 07"));
+
+        dashboard.handle("26").unwrap();
+        assert_eq!(dashboard.selected, 7);
+        assert!(dashboard.message.is_some());
+
+        assert_eq!(dashboard.handle("q").unwrap(), Action::Quit);
+    }
+}
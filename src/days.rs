@@ -0,0 +1,210 @@
+/*
+ * The solutions of every day as a library, so their parsers, types and solvers can be reused
+ * from other days, benchmarks and tests, e.g. `advent_of_code::days::day07::part_one`.
+ * The binaries in `src/bin/` only run them.
+ */
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+use std::cell::Cell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::{Duration, Instant};
+
+use crate::params::Params;
+use crate::Answer;
+
+/// A part of a day that takes the raw input, so tools can run any day by number.
+pub type Solver = fn(&str) -> Option<Answer>;
+
+/// The registered solvers of a day. Days with parameters use their real values.
+#[derive(Debug, Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    /// The solver of part 1 or 2.
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part_one),
+            2 => Some(self.part_two),
+            _ => None,
+        }
+    }
+}
+
+/// All solved days, in order.
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        part_one: |input| day01::part_one(input).map(Answer::from),
+        part_two: |input| day01::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 2,
+        part_one: |input| day02::part_one(input).map(Answer::from),
+        part_two: |input| day02::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 3,
+        part_one: |input| day03::part_one(input).map(Answer::from),
+        part_two: |input| day03::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 4,
+        part_one: |input| day04::part_one(input).map(Answer::from),
+        part_two: |input| day04::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 5,
+        part_one: |input| day05::part_one(input).map(Answer::from),
+        part_two: |input| day05::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 6,
+        part_one: |input| day06::part_one(input).map(Answer::from),
+        part_two: |input| day06::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 7,
+        part_one: |input| day07::part_one(input, &Params::real(day07::PARAMS)).map(Answer::from),
+        part_two: |input| day07::part_two(input, &Params::real(day07::PARAMS)).map(Answer::from),
+    },
+    Day {
+        day: 8,
+        part_one: |input| day08::part_one(input).map(Answer::from),
+        part_two: |input| day08::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 9,
+        part_one: |input| day09::part_one(input).map(Answer::from),
+        part_two: |input| day09::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 10,
+        part_one: |input| day10::part_one(input).map(Answer::from),
+        part_two: day10::part_two,
+    },
+    Day {
+        day: 11,
+        part_one: |input| day11::part_one(&day11::parse(input)).map(Answer::from),
+        part_two: |input| day11::part_two(&day11::parse(input)).map(Answer::from),
+    },
+    Day {
+        day: 12,
+        part_one: |input| day12::part_one(&day12::parse(input)).map(Answer::from),
+        part_two: |input| day12::part_two(&day12::parse(input)).map(Answer::from),
+    },
+    Day {
+        day: 13,
+        part_one: |input| day13::part_one(input).map(Answer::from),
+        part_two: |input| day13::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 14,
+        part_one: |input| day14::part_one(input).map(Answer::from),
+        part_two: |input| day14::part_two(input).map(Answer::from),
+    },
+    Day {
+        day: 15,
+        part_one: |input| {
+            day15::part_one(&day15::parse(input), &Params::real(day15::PARAMS)).map(Answer::from)
+        },
+        part_two: |input| {
+            day15::part_two(&day15::parse(input), &Params::real(day15::PARAMS)).map(Answer::from)
+        },
+    },
+];
+
+/// Looks up the registered solvers of a day.
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
+
+thread_local! {
+    /// Whether panics on this thread are reported by [`run_part`] instead of the panic hook.
+    static QUIET_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Installs a panic hook that stays silent on threads inside [`run_part`] and reports every other panic as before.
+/// The hook is installed once for the whole process, so concurrent calls cannot swap it out under each other.
+fn install_quiet_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET_PANICS.with(Cell::get) {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Runs and times a registered part for tools that show the result themselves.
+/// A panic is returned as its message instead of being printed.
+pub fn run_part(solver: Solver, input: &str) -> Result<(Option<Answer>, Duration), String> {
+    install_quiet_panic_hook();
+    let quiet = QUIET_PANICS.with(|quiet| quiet.replace(true));
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| solver(input)));
+    let elapsed = timer.elapsed();
+    QUIET_PANICS.with(|flag| flag.set(quiet));
+
+    result.map(|answer| (answer, elapsed)).map_err(|panic| {
+        panic
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "the solver panicked".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registry() {
+        assert!(DAYS.windows(2).all(|pair| pair[0].day < pair[1].day));
+        assert_eq!(get(7).map(|entry| entry.day), Some(7));
+        assert!(get(7).unwrap().part(3).is_none());
+    }
+
+    #[test]
+    fn test_run_part() {
+        let example = crate::read_file("examples", 1);
+        let (answer, _) = run_part(get(1).unwrap().part_one, &example).unwrap();
+        assert_eq!(answer, Some(Answer::Integer(24000)));
+
+        let panicking: Solver = |_| panic!("bad input");
+        assert_eq!(run_part(panicking, "").unwrap_err(), "bad input");
+        assert!(!QUIET_PANICS.with(Cell::get));
+    }
+
+    #[test]
+    fn test_run_part_concurrently() {
+        let panicking: Solver = |_| panic!("bad input");
+        let threads: Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(move || run_part(panicking, "").unwrap_err()))
+            .collect();
+        for thread in threads {
+            assert_eq!(thread.join().unwrap(), "bad input");
+        }
+    }
+}
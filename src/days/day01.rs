@@ -0,0 +1,68 @@
+use crate::helpers::generate::Rng;
+
+pub fn to_ints(s: &str) -> Vec<u32> {
+	let mut result = vec![];
+
+	let mut current_sum: u32 = 0;
+	for line in s.lines() {
+		
+		if let Ok(n) = line.parse::<u32>() {
+			current_sum += n;
+		} else {
+			result.push(current_sum);
+			current_sum = 0;
+		}
+	}
+	if current_sum > 0 {
+		result.push(current_sum);
+	}
+
+	result
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(*to_ints(input).iter().max().unwrap())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+	let mut elves = to_ints(input);
+	elves.sort_unstable();
+    Some(elves.iter().rev().take(3).sum())
+}
+
+/// Random snack lists, one group of calories per elf.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let elves: Vec<String> = (0..size)
+		.map(|_| {
+			let snack_count = rng.range(1, 8);
+			let snacks: Vec<String> = (0..snack_count).map(|_| rng.range(1, 20000).to_string()).collect();
+			snacks.join("\n")
+		})
+		.collect();
+	elves.join("\n\n") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 1,
+        example: (Some(24000), Some(45000)),
+    }
+
+    #[test]
+    fn test_generated() {
+        generate::check(1000, 50, generate, |input| {
+            let mut elves: Vec<u32> = input
+                .split("\n\n")
+                .map(|elf| elf.lines().map(|line| line.parse::<u32>().unwrap()).sum())
+                .collect();
+            elves.sort_unstable_by(|a, b| b.cmp(a));
+
+            assert_eq!(part_one(input), Some(elves[0]));
+            assert_eq!(part_two(input), Some(elves.iter().take(3).sum()));
+        });
+    }
+}
@@ -0,0 +1,155 @@
+use crate::helpers::generate::Rng;
+
+#[derive(Copy, Clone)]
+pub enum RpsType {
+	Rock,
+	Paper,
+	Scissors,
+}
+
+pub enum GameResult {
+	Win,
+	Lose,
+	Draw,
+}
+
+impl From<&str> for RpsType {
+	fn from(s: &str) -> Self {
+		match s {
+			"A" | "X" => RpsType::Rock,
+			"B" | "Y" => RpsType::Paper,
+			_ => RpsType::Scissors,
+		}
+	}
+}
+
+impl From<&str> for GameResult {
+	fn from(s: &str) -> Self {
+		match s {
+			"X" => GameResult::Lose,
+			"Y" => GameResult::Draw,
+			_ => GameResult::Win,
+		} 
+	}
+}
+
+impl RpsType {
+	fn compare(&self, other: &RpsType) -> GameResult {
+		match (self, other) {
+			(&RpsType::Rock, &RpsType::Paper) => GameResult::Lose,
+			(&RpsType::Rock, &RpsType::Scissors) => GameResult::Win,
+			(&RpsType::Paper, &RpsType::Rock) => GameResult::Win,
+			(&RpsType::Paper, &RpsType::Scissors) => GameResult::Lose,
+			(&RpsType::Scissors, &RpsType::Rock) => GameResult::Lose,
+			(&RpsType::Scissors, &RpsType::Paper) => GameResult::Win,
+			_ => GameResult::Draw,
+		}
+	}
+
+	fn to_points(self) -> u32 {
+		match self {
+			RpsType::Rock => 1,
+			RpsType::Paper => 2,
+			RpsType::Scissors => 3,
+		}
+	}
+}
+
+impl GameResult {
+	fn to_points(&self) -> u32 {
+		match self {
+			GameResult::Win => 6,
+			GameResult::Lose => 0,
+			GameResult::Draw => 3,
+		}
+	}
+}
+
+pub fn get_points(enemy_play: &RpsType, self_play: &RpsType) -> u32 {
+	let result = self_play.compare(enemy_play);
+	result.to_points() + self_play.to_points()
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+	let mut points = 0;
+    for line in input.lines() {
+		let mut player_inputs = line.split_whitespace();
+		let enemy_play = RpsType::from(player_inputs.next().unwrap());
+		let self_play = RpsType::from(player_inputs.next().unwrap());
+
+		points += get_points(&enemy_play, &self_play);
+	}
+
+	Some(points)
+}
+
+pub fn get_wanted_rps_type(enemy_input: &RpsType, wanted_result: &GameResult) -> RpsType {
+	match (enemy_input, wanted_result) {
+		(RpsType::Rock, GameResult::Win) => RpsType::Paper,
+		(RpsType::Rock, GameResult::Lose) => RpsType::Scissors,
+		(RpsType::Paper, GameResult::Win) => RpsType::Scissors,
+		(RpsType::Paper, GameResult::Lose) => RpsType::Rock,
+		(RpsType::Scissors, GameResult::Win) => RpsType::Rock,
+		(RpsType::Scissors, GameResult::Lose) => RpsType::Paper,
+		(_, _) => *enemy_input,
+	}
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut points = 0;
+	for line in input.lines() {
+		let mut inputs = line.split_whitespace();
+
+		let enemy_play = RpsType::from(inputs.next().unwrap());
+		let target_result = GameResult::from(inputs.next().unwrap());
+
+		let self_play = get_wanted_rps_type(&enemy_play, &target_result);
+		points += get_points(&enemy_play, &self_play);
+	}
+
+	Some(points)
+}
+
+/// Random strategy guide lines.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| format!("{} {}\n", rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z'])))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 2,
+        example: (Some(15), Some(12)),
+    }
+
+    #[test]
+    fn test_generated() {
+        generate::check(1000, 100, generate, |input| {
+            let rounds: Vec<(u32, u32)> = input
+                .lines()
+                .map(|line| {
+                    let bytes = line.as_bytes();
+                    ((bytes[0] - b'A') as u32, (bytes[2] - b'X') as u32)
+                })
+                .collect();
+
+            // shapes and outcomes as 0, 1, 2; the shape played wins against the previous one.
+            let by_shape = rounds
+                .iter()
+                .map(|(enemy, own)| own + 1 + (own + 4 - enemy) % 3 * 3)
+                .sum();
+            let by_outcome = rounds
+                .iter()
+                .map(|(enemy, outcome)| (enemy + outcome + 2) % 3 + 1 + outcome * 3)
+                .sum();
+
+            assert_eq!(part_one(input), Some(by_shape));
+            assert_eq!(part_two(input), Some(by_outcome));
+        });
+    }
+}
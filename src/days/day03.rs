@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use crate::helpers::generate::Rng;
+
+pub struct Rucksack {
+	pub first_half: HashSet<char>,
+	pub second_half: HashSet<char>,
+}
+
+pub fn get_priority(character: &char) -> u32 {
+	let ascii = *character as u32;
+	if character.is_lowercase() {
+		ascii - 96
+	} else {
+		// has higher priority in story
+		ascii - 64 + 26
+	}
+}
+
+impl Rucksack {
+	fn calculate_duplicate_priority_sum(&self) -> u32 {
+		let mut sum = 0;
+		for c in self.first_half.iter() {
+			if self.second_half.contains(c) {
+				sum += get_priority(c);
+			}
+		}
+		sum
+	}
+}
+
+impl From<&str> for Rucksack {
+	fn from(s: &str) -> Self {
+		let mut first_half = HashSet::new();
+		let mut second_half = HashSet::new();
+		
+		let sacks = s.split_at(s.len() / 2);
+		for c in sacks.0.chars() {
+			first_half.insert(c);
+		}
+		for c in sacks.1.chars() {
+			second_half.insert(c);
+		}
+		Rucksack { first_half, second_half }
+	}
+}
+
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut priority_sum = 0;
+	for line in input.lines() {
+		let rucksack = Rucksack::from(line);
+		priority_sum += rucksack.calculate_duplicate_priority_sum();
+	}
+	Some(priority_sum)
+}
+
+pub struct ElvGroup {
+	pub sack1: HashSet<char>,
+	pub sack2: HashSet<char>,
+	pub sack3: HashSet<char>,
+}
+
+impl ElvGroup {
+	fn new(s1: &str, s2: &str, s3: &str) -> Self {
+		let sack1 = HashSet::from_iter(s1.chars());
+		let sack2 = HashSet::from_iter(s2.chars());
+		let sack3 = HashSet::from_iter(s3.chars());
+		
+		ElvGroup { sack1, sack2, sack3 }
+	}
+
+	fn get_common_badge(&self) -> char {
+		let mut common_badge = ' ';
+		for c in self.sack1.iter() {
+			if self.sack2.contains(c) && self.sack3.contains(c) {
+				common_badge = *c;
+				break;
+			}
+		}
+		common_badge
+	}
+
+	pub fn get_common_badge_priority(&self) -> u32 {
+		get_priority(&self.get_common_badge())
+	}
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+	let mut priority_sum = 0;
+	let mut lines = input.lines();
+    while let(Some(s1), Some(s2), Some(s3)) = (lines.next(), lines.next(), lines.next()) {
+		let elv_group = ElvGroup::new(s1, s2, s3);
+		priority_sum += elv_group.get_common_badge_priority();
+	}
+
+	Some(priority_sum)
+}
+
+/// Random groups of three rucksacks.
+/// Each rucksack shares exactly one item between its compartments, each group exactly one badge.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+	let mut output = String::new();
+
+	for _ in 0..size {
+		let mut others = items.clone();
+		let badge = others.swap_remove(rng.index(others.len()));
+		rng.shuffle(&mut others);
+
+		// every rucksack of the group uses its own items, so only the badge is shared by all three.
+		for pool in others.chunks(others.len() / 3).take(3) {
+			output.push_str(&generate_rucksack(rng, badge, pool));
+			output.push('\n');
+		}
+	}
+	output
+}
+
+fn generate_rucksack(rng: &mut Rng, badge: char, pool: &[char]) -> String {
+	let common = if rng.chance(0.25) { badge } else { *rng.pick(pool) };
+	let (first_pool, second_pool) = pool.split_at(pool.len() / 2);
+	let first_pool: Vec<char> = first_pool.iter().copied().filter(|c| *c != common).collect();
+	let second_pool: Vec<char> = second_pool.iter().copied().filter(|c| *c != common).collect();
+
+	let mut first = vec![common];
+	let mut second = vec![common];
+	if common != badge {
+		first.push(badge);
+	}
+
+	let length = rng.range(2, 12) as usize;
+	while first.len() < length {
+		first.push(*rng.pick(&first_pool));
+	}
+	while second.len() < first.len() {
+		second.push(*rng.pick(&second_pool));
+	}
+
+	rng.shuffle(&mut first);
+	rng.shuffle(&mut second);
+	first.into_iter().chain(second).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 3,
+        example: (Some(157), Some(70)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::HashSet;
+
+        fn single_common(sets: &[HashSet<char>]) -> u32 {
+            let common: Vec<char> = sets[0]
+                .iter()
+                .filter(|c| sets.iter().all(|set| set.contains(c)))
+                .copied()
+                .collect();
+            assert_eq!(common.len(), 1, "expected exactly one common item");
+            match common[0] {
+                c @ 'a'..='z' => c as u32 - 'a' as u32 + 1,
+                c => c as u32 - 'A' as u32 + 27,
+            }
+        }
+
+        generate::check(1000, 30, generate, |input| {
+            let lines: Vec<&str> = input.lines().collect();
+            let compartments: u32 = lines
+                .iter()
+                .map(|line| {
+                    let (first, second) = line.split_at(line.len() / 2);
+                    single_common(&[first.chars().collect(), second.chars().collect()])
+                })
+                .sum();
+            let badges: u32 = lines
+                .chunks(3)
+                .map(|group| single_common(&group.iter().map(|line| line.chars().collect()).collect::<Vec<_>>()))
+                .sum();
+
+            assert_eq!(part_one(input), Some(compartments));
+            assert_eq!(part_two(input), Some(badges));
+        });
+    }
+}
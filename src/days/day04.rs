@@ -0,0 +1,120 @@
+use crate::helpers::generate::Rng;
+
+pub struct Range {
+	pub start: u32,
+	pub end: u32,
+}
+
+impl Range {
+	fn contains(&self, other: &Range) -> bool {
+		self.start <= other.start && self.end >= other.end
+	}
+
+	fn overlaps(&self, other: &Range) -> bool {
+		self.start <= other.end && self.end >= other.start
+	}
+}
+
+impl From<&str> for Range {
+	fn from(s: &str) -> Self {
+		let mut range = s.split('-');
+		let start = range.next().unwrap().parse::<u32>().unwrap();
+		let end = range.next().unwrap().parse::<u32>().unwrap();
+		Range { start, end }
+	}
+}
+
+pub struct ElvesPair {
+	pub elv1_range: Range,
+	pub elv2_range: Range,
+}
+
+impl ElvesPair {
+	fn one_contains_other(&self) -> bool {
+		self.elv1_range.contains(&self.elv2_range) || self.elv2_range.contains(&self.elv1_range)
+	}
+
+	fn overlaps(&self) -> bool {
+		self.elv1_range.overlaps(&self.elv2_range)
+	}
+}
+
+impl From<&str> for ElvesPair {
+	fn from(s: &str) -> Self {
+		let mut pair = s.split(",");
+		let elv1_range = Range::from(pair.next().unwrap());
+		let elv2_range = Range::from(pair.next().unwrap());
+		ElvesPair { elv1_range, elv2_range }
+	}
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut contains_count = 0;
+	for line in input.lines() {
+		let pair = ElvesPair::from(line);
+		if pair.one_contains_other() {
+			contains_count += 1;
+		}
+	}
+	Some(contains_count)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut overlap_count = 0;
+	for line in input.lines() {
+		let pair = ElvesPair::from(line);
+		if pair.overlaps() {
+			overlap_count += 1;
+		}
+	}
+	Some(overlap_count)
+}
+
+/// Random pairs of section assignments.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let range = |rng: &mut Rng| {
+		let start = rng.range(1, 99);
+		format!("{}-{}", start, rng.range(start, 99))
+	};
+	(0..size)
+		.map(|_| format!("{},{}\n", range(rng), range(rng)))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 4,
+        example: (Some(2), Some(4)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::HashSet;
+
+        generate::check(1000, 100, generate, |input| {
+            let pairs: Vec<(HashSet<u32>, HashSet<u32>)> = input
+                .lines()
+                .map(|line| {
+                    let sections: Vec<HashSet<u32>> = line
+                        .split(',')
+                        .map(|range| {
+                            let (start, end) = range.split_once('-').unwrap();
+                            (start.parse().unwrap()..=end.parse().unwrap()).collect()
+                        })
+                        .collect();
+                    (sections[0].clone(), sections[1].clone())
+                })
+                .collect();
+
+            let contained = pairs.iter().filter(|(a, b)| a.is_subset(b) || b.is_subset(a)).count();
+            let overlapping = pairs.iter().filter(|(a, b)| !a.is_disjoint(b)).count();
+
+            assert_eq!(part_one(input), Some(contained as u32));
+            assert_eq!(part_two(input), Some(overlapping as u32));
+        });
+    }
+}
@@ -0,0 +1,748 @@
+use std::fmt::{self, Display};
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use crate::helpers::generate::Rng;
+use crate::helpers::parse::{blocks, ParseError};
+use crate::runner::Variant;
+use crate::scan;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stack {
+	cargos: Vec<char>,
+}
+
+impl Stack {
+	fn new() -> Self {
+		Stack { cargos: Vec::new() }
+	}
+
+	pub fn push(&mut self, c: char) {
+		self.cargos.push(c);
+	}
+
+	pub fn pop(&mut self) -> Option<char> {
+		self.cargos.pop()
+	}
+
+	/// Inserts a crate with `index` crates below it.
+	pub fn insert(&mut self, index: usize, c: char) {
+		self.cargos.insert(index, c);
+	}
+
+	/// The crates from the bottom up.
+	pub fn crates(&self) -> &[char] {
+		&self.cargos
+	}
+
+	pub fn peek(&self) -> Option<&char> {
+		self.cargos.last()
+	}
+
+	/// Removes the top `count` crates, bottom one first.
+	pub fn take(&mut self, count: usize) -> Vec<char> {
+		self.cargos.split_off(self.cargos.len() - count)
+	}
+
+	/// Puts the crates on top of the stack, first one lowest.
+	pub fn put(&mut self, cargos: impl IntoIterator<Item = char>) {
+		self.cargos.extend(cargos);
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warehouse {
+	stacks: Vec<Stack>,
+}
+
+impl Warehouse {
+	/// Parses a stack drawing. The numbered footer determines the column of every stack,
+	/// so lines may be ragged or trimmed and labels may have several digits.
+	pub fn parse(drawing: &str) -> Result<Self, ParseError> {
+		let lines: Vec<&str> = drawing.lines().map(str::trim_end).collect();
+		let footer = lines
+			.iter()
+			.rposition(|line| !line.is_empty())
+			.ok_or_else(|| ParseError::new("empty stack drawing"))?;
+		let labels = parse_footer(lines[footer]).map_err(|e| e.at_line(footer + 1))?;
+
+		let mut stacks = vec![Stack::new(); labels.len()];
+		// from the bottom up, so every crate lands on the one below it.
+		for (index, line) in lines[..footer].iter().enumerate().rev() {
+			let level = footer - 1 - index;
+			for (column, cargo) in parse_row(line).map_err(|e| e.at_line(index + 1))? {
+				let error = |message: String| ParseError::new(format!("column {}: {}", column + 1, message)).at_line(index + 1);
+				let stack = labels
+					.iter()
+					.position(|label| label.start < column + 3 && column < label.end)
+					.ok_or_else(|| error(format!("crate `{}` is not above a stack label", cargo)))?;
+				if stacks[stack].cargos.len() < level {
+					return Err(error(format!("crate `{}` floats above an empty slot of stack {}", cargo, stack + 1)));
+				}
+				if stacks[stack].cargos.len() > level {
+					return Err(error(format!("crate `{}` overlaps another crate of stack {}", cargo, stack + 1)));
+				}
+				stacks[stack].push(cargo);
+			}
+		}
+		Ok(Warehouse { stacks })
+	}
+}
+
+/// The columns of the stack labels `1 2 3 …`, which have to be numbered in order.
+fn parse_footer(line: &str) -> Result<Vec<Range<usize>>, ParseError> {
+	let chars: Vec<char> = line.chars().collect();
+	let mut labels = Vec::new();
+	let mut column = 0;
+	while column < chars.len() {
+		if chars[column] == ' ' {
+			column += 1;
+			continue;
+		}
+		let end = (column..chars.len()).find(|end| chars[*end] == ' ').unwrap_or(chars.len());
+		let label: String = chars[column..end].iter().collect();
+		if label.parse::<usize>().ok() != Some(labels.len() + 1) {
+			return Err(ParseError::new(format!(
+				"column {}: expected the label of stack {}, found `{}`",
+				column + 1,
+				labels.len() + 1,
+				label
+			)));
+		}
+		labels.push(column..end);
+		column = end;
+	}
+	if labels.is_empty() {
+		return Err(ParseError::new("missing stack labels"));
+	}
+	Ok(labels)
+}
+
+/// The crates `[X]` in a row of the drawing, with the column of their opening bracket.
+fn parse_row(line: &str) -> Result<Vec<(usize, char)>, ParseError> {
+	let chars: Vec<char> = line.chars().collect();
+	let mut cargos = Vec::new();
+	let mut column = 0;
+	while column < chars.len() {
+		match chars[column..] {
+			[' ', ..] => column += 1,
+			['[', cargo, ']', ..] if !matches!(cargo, ' ' | '[' | ']') => {
+				cargos.push((column, cargo));
+				column += 3;
+			}
+			['[', ..] => return Err(ParseError::new(format!("column {}: expected a crate like `[A]`", column + 1))),
+			[c, ..] => return Err(ParseError::new(format!("column {}: unexpected `{}`", column + 1, c))),
+			[] => unreachable!(),
+		}
+	}
+	Ok(cargos)
+}
+
+/// An invalid move for the current state of the warehouse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+	/// The label of a stack that does not exist.
+	NoSuchStack(usize),
+	NotEnoughCrates { stack: usize, requested: usize, available: usize },
+}
+
+impl Display for MoveError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			MoveError::NoSuchStack(label) => write!(f, "stack {} does not exist", label),
+			MoveError::NotEnoughCrates { stack, requested, available } => {
+				write!(f, "cannot move {} crates from stack {}, it holds {}", requested, stack, available)
+			}
+		}
+	}
+}
+
+impl std::error::Error for MoveError {}
+
+/// A move instruction that could not be applied, with its (1-based) line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OperationError {
+	pub line: usize,
+	pub kind: OperationErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OperationErrorKind {
+	/// The line is not a `move {} from {} to {}` instruction.
+	Parse(ParseError),
+	Move(MoveError),
+}
+
+impl Display for OperationErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			OperationErrorKind::Parse(e) => write!(f, "{}", e),
+			OperationErrorKind::Move(e) => write!(f, "{}", e),
+		}
+	}
+}
+
+impl Display for OperationError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "line {}: {}", self.line, self.kind)
+	}
+}
+
+impl std::error::Error for OperationError {}
+
+impl From<OperationError> for ParseError {
+	fn from(e: OperationError) -> Self {
+		ParseError::new(e.kind.to_string()).at_line(e.line)
+	}
+}
+
+/// A crane model, which decides in which order the moved crates end up on the destination stack.
+/// Moves are validated by the warehouse before they reach the crane: `source` holds at least `count` crates
+/// and is a different stack than `destination`.
+pub trait CrateMover {
+	fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack);
+
+	/// Reverts [`CrateMover::move_crates`]: puts the top `count` crates of `destination` back onto `source`.
+	fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack);
+}
+
+/// Moves one crate at a time, which reverses their order.
+#[derive(Debug, Clone, Copy)]
+pub struct CrateMover9000;
+
+impl CrateMover for CrateMover9000 {
+	fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		destination.put(source.take(count).into_iter().rev());
+	}
+
+	fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		source.put(destination.take(count).into_iter().rev());
+	}
+}
+
+/// Moves all crates at once, which keeps their order.
+#[derive(Debug, Clone, Copy)]
+pub struct CrateMover9001;
+
+impl CrateMover for CrateMover9001 {
+	fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		destination.put(source.take(count));
+	}
+
+	fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		source.put(destination.take(count));
+	}
+}
+
+/// Lifts at most `capacity` crates at once, keeping the order within each batch.
+/// A capacity of 1 behaves like the 9000, `NonZeroUsize::MAX` like the 9001.
+#[derive(Debug, Clone, Copy)]
+pub struct BatchCrateMover {
+	pub capacity: NonZeroUsize,
+}
+
+impl CrateMover for BatchCrateMover {
+	fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		let moved = source.take(count);
+		// the top batch is lifted first.
+		for batch in moved.rchunks(self.capacity.get()) {
+			destination.put(batch.iter().copied());
+		}
+	}
+
+	fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		let moved = destination.take(count);
+		// the first batch on the destination came from the top of the source.
+		for batch in moved.chunks(self.capacity.get()).rev() {
+			source.put(batch.iter().copied());
+		}
+	}
+}
+
+/// Moves the crates one by one, like the first version of the solution.
+/// Keeping their order means inserting each crate below the ones moved before, which is quadratic per move.
+/// Kept as a reference for the bulk moves above.
+#[derive(Debug, Clone, Copy)]
+pub struct CrateByCrateMover {
+	pub keep_order: bool,
+}
+
+impl CrateMover for CrateByCrateMover {
+	fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		let index = destination.crates().len();
+		for _ in 0..count {
+			let c = source.pop().unwrap();
+			match self.keep_order {
+				true => destination.insert(index, c),
+				false => destination.push(c),
+			}
+		}
+	}
+
+	fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+		let index = source.crates().len();
+		for _ in 0..count {
+			let c = destination.pop().unwrap();
+			match self.keep_order {
+				true => source.insert(index, c),
+				false => source.push(c),
+			}
+		}
+	}
+}
+
+/// Draws the warehouse in the format of the puzzle input, including the footer with the stack labels.
+impl Display for Warehouse {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let height = self.stacks.iter().map(|stack| stack.cargos.len()).max().unwrap_or(0);
+		for level in (0..height).rev() {
+			let row: Vec<String> = self.stacks.iter()
+				.map(|stack| stack.cargos.get(level).map_or("   ".to_string(), |c| format!("[{}]", c)))
+				.collect();
+			writeln!(f, "{}", row.join(" "))?;
+		}
+		let labels: Vec<String> = (1..=self.stacks.len()).map(|label| format!("{:^3}", label)).collect();
+		write!(f, "{}", labels.join(" "))
+	}
+}
+
+impl Warehouse {
+	pub fn apply_str_operations(&mut self, operations: &str, mover: &dyn CrateMover) -> Result<(), OperationError> {
+		self.replay(operations, mover, |_, _| {})
+	}
+
+	/// Applies the operations one by one and calls `step` with each operation and the state after it.
+	pub fn replay(&mut self, operations: &str, mover: &dyn CrateMover, mut step: impl FnMut(&str, &Warehouse)) -> Result<(), OperationError> {
+		for (index, operation) in operations.lines().enumerate() {
+			let error = |kind| OperationError { line: index + 1, kind };
+			let (count, source, destination) = parse_operation(operation).map_err(|e| error(OperationErrorKind::Parse(e)))?;
+			self.apply_move(mover, count, source, destination)
+				.map_err(|e| error(OperationErrorKind::Move(e)))?;
+			step(operation, self);
+		}
+		Ok(())
+	}
+
+	/// Reverts the operations, last one first, so the warehouse ends up in the state before them.
+	/// Fails if an operation could not have led to the current state.
+	pub fn undo_str_operations(&mut self, operations: &str, mover: &dyn CrateMover) -> Result<(), OperationError> {
+		let parsed = operations
+			.lines()
+			.enumerate()
+			.map(|(index, operation)| {
+				parse_operation(operation).map_err(|e| OperationError { line: index + 1, kind: OperationErrorKind::Parse(e) })
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+		for (index, (count, source, destination)) in parsed.into_iter().enumerate().rev() {
+			self.undo_move(mover, count, source, destination)
+				.map_err(|e| OperationError { line: index + 1, kind: OperationErrorKind::Move(e) })?;
+		}
+		Ok(())
+	}
+
+	/// Reverts a move of `count` crates between two stacks, given by their 1-based labels.
+	/// Invalid moves leave the warehouse unchanged.
+	pub fn undo_move(&mut self, mover: &dyn CrateMover, count: usize, source: usize, destination: usize) -> Result<(), MoveError> {
+		let source_index = self.get_stack_index(source)?;
+		let destination_index = self.get_stack_index(destination)?;
+		let available = self.stacks[destination_index].cargos.len();
+		if count > available {
+			return Err(MoveError::NotEnoughCrates { stack: destination, requested: count, available });
+		}
+		if source_index != destination_index {
+			let (source, destination) = self.get_stack_pair(source_index, destination_index);
+			mover.undo_crates(count, source, destination);
+		}
+		Ok(())
+	}
+
+	/// Moves `count` crates between two stacks, given by their 1-based labels.
+	/// Invalid moves leave the warehouse unchanged, and so do moves from a stack onto itself.
+	pub fn apply_move(&mut self, mover: &dyn CrateMover, count: usize, source: usize, destination: usize) -> Result<(), MoveError> {
+		let source_index = self.get_stack_index(source)?;
+		let destination_index = self.get_stack_index(destination)?;
+		let available = self.stacks[source_index].cargos.len();
+		if count > available {
+			return Err(MoveError::NotEnoughCrates { stack: source, requested: count, available });
+		}
+		// lifting crates and putting them back where they were changes nothing, whatever the crane.
+		if source_index != destination_index {
+			let (source, destination) = self.get_stack_pair(source_index, destination_index);
+			mover.move_crates(count, source, destination);
+		}
+		Ok(())
+	}
+
+	fn get_stack_index(&self, label: usize) -> Result<usize, MoveError> {
+		if label == 0 || label > self.stacks.len() {
+			return Err(MoveError::NoSuchStack(label));
+		}
+		Ok(label - 1)
+	}
+
+	/// The stacks at two different indices, borrowed at the same time.
+	fn get_stack_pair(&mut self, first: usize, second: usize) -> (&mut Stack, &mut Stack) {
+		if first < second {
+			let (lower, upper) = self.stacks.split_at_mut(second);
+			(&mut lower[first], &mut upper[0])
+		} else {
+			let (lower, upper) = self.stacks.split_at_mut(first);
+			(&mut upper[0], &mut lower[second])
+		}
+	}
+
+	pub fn stacks(&self) -> &[Stack] {
+		&self.stacks
+	}
+
+	/// The top crate of every stack, skipping empty ones.
+	pub fn top_crates(&self) -> Vec<char> {
+		self.stacks.iter().filter_map(|stack| stack.peek().copied()).collect()
+	}
+}
+
+fn parse_operation(operation: &str) -> Result<(usize, usize, usize), ParseError> {
+	scan!("move {} from {} to {}", operation => usize, usize, usize)
+}
+
+/// The warehouse before the operations, given the state after them.
+/// The result is checked by applying the operations to it again.
+pub fn reverse_solve(last: &Warehouse, operations: &str, mover: &dyn CrateMover) -> Result<Warehouse, ParseError> {
+	let mut first = last.clone();
+	first.undo_str_operations(operations, mover)?;
+
+	let mut replayed = first.clone();
+	replayed.apply_str_operations(operations, mover)?;
+	if replayed != *last {
+		return Err(ParseError::new(format!("replaying the moves does not lead to the given state:\n{}", replayed)));
+	}
+	Ok(first)
+}
+
+/// Splits the input into the warehouse drawn in it and the move instructions.
+pub fn parse_input(input: &str) -> Result<(Warehouse, &str), ParseError> {
+	let mut blocks = blocks(input);
+	let stack_input = blocks.next().ok_or_else(|| ParseError::new("missing stack drawing"))?;
+	let command_input = blocks.next().ok_or_else(|| ParseError::new("missing move instructions"))?;
+	let warehouse = Warehouse::parse(stack_input).map_err(|e| e.context("stack drawing"))?;
+	Ok((warehouse, command_input))
+}
+
+fn get_movement_result(input: &str, mover: &dyn CrateMover) -> Result<String, ParseError> {
+	let (mut warehouse, command_input) = parse_input(input)?;
+	warehouse
+		.apply_str_operations(command_input, mover)
+		.map_err(|e| ParseError::from(e).context("move instructions"))?;
+
+	Ok(warehouse.top_crates().iter().collect())
+}
+
+pub const PART_ONE_VARIANTS: &[Variant<str, String>] = &[("fast", part_one), ("naive", part_one_naive)];
+pub const PART_TWO_VARIANTS: &[Variant<str, String>] = &[("fast", part_two), ("naive", part_two_naive)];
+
+pub fn part_one(input: &str) -> Option<String> {
+	Some(get_movement_result(input, &CrateMover9000).unwrap())
+}
+
+pub fn part_one_naive(input: &str) -> Option<String> {
+	Some(get_movement_result(input, &CrateByCrateMover { keep_order: false }).unwrap())
+}
+
+pub fn part_two(input: &str) -> Option<String> {
+    Some(get_movement_result(input, &CrateMover9001).unwrap())
+}
+
+pub fn part_two_naive(input: &str) -> Option<String> {
+	Some(get_movement_result(input, &CrateByCrateMover { keep_order: true }).unwrap())
+}
+
+/// A random stack drawing followed by moves that never empty a stack.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	generate_warehouse(rng, size, 6)
+}
+
+/// Like [`generate`], but with `moves` moves and stacks of up to `max_height` crates,
+/// e.g. to benchmark moves of many crates at once.
+pub fn generate_warehouse(rng: &mut Rng, moves: usize, max_height: usize) -> String {
+	let stack_count = rng.range(2, 9) as usize;
+	// the first stack starts with at least two crates, so there is always a valid move.
+	let mut stacks: Vec<Vec<char>> = (0..stack_count)
+		.map(|index| {
+			let height = rng.range(if index == 0 { 2 } else { 1 }, max_height.max(2) as i64);
+			(0..height).map(|_| (b'A' + rng.below(26) as u8) as char).collect()
+		})
+		.collect();
+
+	let warehouse = Warehouse { stacks: stacks.iter().map(|cargos| Stack { cargos: cargos.clone() }).collect() };
+	let mut output = format!("{}\n\n", warehouse);
+
+	for _ in 0..moves {
+		let sources: Vec<usize> = (0..stack_count).filter(|index| stacks[*index].len() >= 2).collect();
+		if sources.is_empty() {
+			break;
+		}
+		let source = *rng.pick(&sources);
+		let destination = (source + 1 + rng.index(stack_count - 1)) % stack_count;
+		let count = rng.range(1, stacks[source].len() as i64 - 1) as usize;
+
+		let remaining = stacks[source].len() - count;
+		let moved = stacks[source].split_off(remaining);
+		stacks[destination].extend(moved);
+		output.push_str(&format!("move {} from {} to {}\n", count, source + 1, destination + 1));
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 5,
+        variants: (PART_ONE_VARIANTS, PART_TWO_VARIANTS),
+        example: (Some("CMZ".to_string()), Some("MCD".to_string())),
+    }
+
+    fn batch(capacity: usize) -> BatchCrateMover {
+        BatchCrateMover { capacity: NonZeroUsize::new(capacity).unwrap() }
+    }
+
+    #[test]
+    fn test_crate_movers() {
+        let example = crate::read_file("examples", 5);
+        // the example moves at most three crates at once.
+        for (capacity, expected) in [(1, "CMZ"), (2, "MCZ"), (3, "MCD")] {
+            assert_eq!(get_movement_result(&example, &batch(capacity)).unwrap(), expected);
+        }
+
+        let drawing = "[A]    \n[B] [C]\n[D] [E]\n 1   2 ";
+        let mut warehouse = Warehouse::parse(drawing).unwrap();
+        warehouse.apply_move(&batch(2), 3, 1, 2).unwrap();
+        assert_eq!(warehouse.stacks[1].cargos, ['E', 'C', 'B', 'A', 'D']);
+    }
+
+    #[test]
+    fn test_custom_crate_mover() {
+        // only uses the public stack operations, like a crane model defined outside this module.
+        struct TopFirstMover;
+        impl CrateMover for TopFirstMover {
+            fn move_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+                let index = destination.crates().len();
+                for _ in 0..count {
+                    let c = source.pop().unwrap();
+                    destination.insert(index, c);
+                }
+            }
+
+            fn undo_crates(&self, count: usize, source: &mut Stack, destination: &mut Stack) {
+                let moved = destination.take(count);
+                source.put(moved);
+            }
+        }
+
+        let example = crate::read_file("examples", 5);
+        assert_eq!(get_movement_result(&example, &TopFirstMover).unwrap(), "MCD");
+        let (first, operations) = parse_input(&example).unwrap();
+        let mut last = first.clone();
+        last.apply_str_operations(operations, &TopFirstMover).unwrap();
+        assert_eq!(reverse_solve(&last, operations, &TopFirstMover).unwrap(), first);
+    }
+
+    #[test]
+    fn test_same_stack_moves() {
+        let first = Warehouse::parse("[A]    \n[B]    \n[C] [D]\n 1   2 ").unwrap();
+        let movers: [&dyn CrateMover; 6] = [
+            &CrateMover9000,
+            &CrateMover9001,
+            &batch(2),
+            &CrateByCrateMover { keep_order: false },
+            &CrateByCrateMover { keep_order: true },
+            &batch(1),
+        ];
+        for mover in movers {
+            let mut warehouse = first.clone();
+            warehouse.apply_move(mover, 3, 1, 1).unwrap();
+            assert_eq!(warehouse, first);
+            warehouse.undo_move(mover, 2, 1, 1).unwrap();
+            assert_eq!(warehouse, first);
+            assert_eq!(reverse_solve(&first, "move 3 from 1 to 1\nmove 1 from 2 to 2", mover).unwrap(), first);
+        }
+    }
+
+    #[test]
+    fn test_invalid_moves() {
+        let mut warehouse = Warehouse::parse("[A]    \n[B] [C]\n 1   2 ").unwrap();
+        assert_eq!(
+            warehouse.apply_move(&CrateMover9000, 3, 1, 2),
+            Err(MoveError::NotEnoughCrates { stack: 1, requested: 3, available: 2 })
+        );
+        assert_eq!(warehouse.apply_move(&CrateMover9001, 1, 1, 3), Err(MoveError::NoSuchStack(3)));
+        assert_eq!(warehouse.apply_move(&CrateMover9001, 1, 0, 2), Err(MoveError::NoSuchStack(0)));
+        assert_eq!(warehouse.stacks[0].cargos, ['B', 'A']);
+
+        assert_eq!(warehouse.apply_move(&CrateMover9000, 2, 1, 1), Ok(()));
+        assert_eq!(warehouse.stacks[0].cargos, ['B', 'A']);
+        assert_eq!(
+            warehouse.apply_move(&CrateMover9000, 3, 1, 1),
+            Err(MoveError::NotEnoughCrates { stack: 1, requested: 3, available: 2 })
+        );
+
+        let error = warehouse.apply_str_operations("move 1 from 1 to 2\nmove 5 from 2 to 1", &CrateMover9000).unwrap_err();
+        assert_eq!(
+            error,
+            OperationError {
+                line: 2,
+                kind: OperationErrorKind::Move(MoveError::NotEnoughCrates { stack: 2, requested: 5, available: 2 }),
+            }
+        );
+        assert_eq!(error.to_string(), "line 2: cannot move 5 crates from stack 2, it holds 2");
+        let error = warehouse.apply_str_operations("move 1 from 1\n", &CrateMover9000).unwrap_err();
+        assert_eq!(error.line, 1);
+        assert!(matches!(error.kind, OperationErrorKind::Parse(_)));
+
+        let error = warehouse.undo_str_operations("move 1 from 1 to 2\nmove 9 from 1 to 2", &CrateMover9000).unwrap_err();
+        assert_eq!(
+            error,
+            OperationError {
+                line: 2,
+                kind: OperationErrorKind::Move(MoveError::NotEnoughCrates { stack: 2, requested: 9, available: 2 }),
+            }
+        );
+        let error = get_movement_result("[A]\n 1 \n\nmove 1 from 1 to 2", &CrateMover9000).unwrap_err();
+        assert_eq!(error.message(), "move instructions: line 1: stack 2 does not exist");
+    }
+
+    #[test]
+    fn test_render() {
+        let example = crate::read_file("examples", 5);
+        let (drawing, operations) = example.split_once("\n\n").unwrap();
+        let mut warehouse = Warehouse::parse(drawing).unwrap();
+        assert_eq!(warehouse.to_string(), drawing);
+
+        let mut steps = Vec::new();
+        warehouse
+            .replay(operations, &CrateMover9001, |operation, warehouse| steps.push(format!("{}\n{}", operation, warehouse)))
+            .unwrap();
+        assert_eq!(steps.len(), 4);
+        assert_eq!(steps[0], "move 1 from 2 to 1\n[D]        \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ");
+        assert_eq!(steps[3], format!("move 1 from 1 to 2\n{}", warehouse));
+
+        generate::check(200, 20, generate, |input| {
+            let drawing = input.split_once("\n\n").unwrap().0;
+            assert_eq!(Warehouse::parse(drawing).unwrap().to_string(), drawing);
+        });
+    }
+
+    #[test]
+    fn test_reverse_solve() {
+        let example = crate::read_file("examples", 5);
+        let (first, operations) = parse_input(&example).unwrap();
+        let movers: [&dyn CrateMover; 4] = [&CrateMover9000, &CrateMover9001, &batch(2), &CrateByCrateMover { keep_order: true }];
+        for mover in movers {
+            let mut last = first.clone();
+            last.apply_str_operations(operations, mover).unwrap();
+            assert_eq!(reverse_solve(&last, operations, mover).unwrap(), first);
+        }
+
+        let last = Warehouse::parse("[A]    \n 1   2 ").unwrap();
+        let error = reverse_solve(&last, "move 1 from 1 to 2\nmove 2 from 2 to 1", &CrateMover9000).unwrap_err();
+        assert_eq!(error.line(), Some(2));
+        assert_eq!(error.message(), "cannot move 2 crates from stack 1, it holds 1");
+
+        generate::check(200, 30, generate, |input| {
+            let (first, operations) = parse_input(input).unwrap();
+            for mover in [&CrateMover9000 as &dyn CrateMover, &CrateMover9001] {
+                let mut last = first.clone();
+                last.apply_str_operations(operations, mover).unwrap();
+                assert_eq!(reverse_solve(&last, operations, mover).unwrap(), first);
+            }
+        });
+    }
+
+    #[test]
+    fn test_parse_drawing() {
+        let example = crate::read_file("examples", 5);
+        let drawing = example.split_once("\n\n").unwrap().0;
+        let warehouse = Warehouse::parse(drawing).unwrap();
+        let stacks: Vec<&[char]> = warehouse.stacks().iter().map(Stack::crates).collect();
+        assert_eq!(stacks, [&['Z', 'N'][..], &['M', 'C', 'D'], &['P']]);
+        assert_eq!(warehouse.top_crates(), ['N', 'D', 'P']);
+
+        let trimmed: Vec<&str> = drawing.lines().map(str::trim_end).collect();
+        assert_eq!(Warehouse::parse(&trimmed.join("\n")).unwrap(), warehouse);
+
+        // more than nine stacks, and empty ones.
+        let wide = format!(
+            "{}[L]\n[A] [B] [C] [D] [E] [F] [G] [H] [I] [J]     [K]\n 1   2   3   4   5   6   7   8   9  10  11  12 ",
+            " ".repeat(44)
+        );
+        let warehouse = Warehouse::parse(&wide).unwrap();
+        assert_eq!(warehouse.stacks.len(), 12);
+        assert_eq!(warehouse.stacks[9].cargos, ['J']);
+        assert!(warehouse.stacks[10].cargos.is_empty());
+        assert_eq!(warehouse.stacks[11].cargos, ['K', 'L']);
+        assert_eq!(warehouse.top_crates().iter().collect::<String>(), "ABCDEFGHIJL");
+        assert_eq!(warehouse.to_string(), wide);
+    }
+
+    #[test]
+    fn test_parse_drawing_errors() {
+        let error = |drawing: &str| {
+            let error = Warehouse::parse(drawing).unwrap_err();
+            (error.line(), error.message().to_string())
+        };
+        assert_eq!(error("[A] [B]\n[C] {D}\n 1   2"), (Some(2), "column 5: unexpected `{`".to_string()));
+        assert_eq!(error("[A] [B\n 1   2"), (Some(1), "column 5: expected a crate like `[A]`".to_string()));
+        assert_eq!(error("[A]\n    [B]\n 1   2"), (Some(1), "column 1: crate `A` floats above an empty slot of stack 1".to_string()));
+        assert_eq!(error("[A]         [B]\n 1   2"), (Some(1), "column 13: crate `B` is not above a stack label".to_string()));
+        assert_eq!(error("[A] [B]\n 1   3"), (Some(2), "column 6: expected the label of stack 2, found `3`".to_string()));
+        assert_eq!(error("[A] [B]\n"), (Some(1), "column 1: expected the label of stack 1, found `[A]`".to_string()));
+        assert_eq!(error("\n  \n"), (None, "empty stack drawing".to_string()));
+
+        let error = get_movement_result("[A]\n 1 x\n\nmove 1 from 1 to 1", &CrateMover9000).unwrap_err();
+        assert_eq!(error.message(), "stack drawing: line 2: column 4: expected the label of stack 2, found `x`");
+    }
+
+    #[test]
+    fn test_generated() {
+        fn simulate(input: &str, reverse: bool) -> String {
+            let (drawing, moves) = input.split_once("\n\n").unwrap();
+            let rows: Vec<Vec<char>> = drawing.lines().map(|line| line.chars().collect()).collect();
+            let stack_count = (rows[0].len() + 1) / 4;
+            let mut stacks: Vec<Vec<char>> = (0..stack_count)
+                .map(|index| {
+                    rows[..rows.len() - 1]
+                        .iter()
+                        .rev()
+                        .map(|row| row[index * 4 + 1])
+                        .filter(|c| *c != ' ')
+                        .collect()
+                })
+                .collect();
+
+            for line in moves.lines() {
+                let numbers: Vec<usize> = line.split(' ').filter_map(|word| word.parse().ok()).collect();
+                let source = &mut stacks[numbers[1] - 1];
+                let mut moved = source.split_off(source.len() - numbers[0]);
+                if reverse {
+                    moved.reverse();
+                }
+                stacks[numbers[2] - 1].extend(moved);
+            }
+            stacks.iter().map(|stack| stack.last().unwrap()).collect()
+        }
+
+        generate::check(1000, 50, generate, |input| {
+            assert_eq!(part_one(input), Some(simulate(input, true)));
+            assert_eq!(part_two(input), Some(simulate(input, false)));
+            crate::assert_variants_agree(PART_ONE_VARIANTS, input, "part one");
+            crate::assert_variants_agree(PART_TWO_VARIANTS, input, "part two");
+        });
+
+        generate::check(20, 30, |rng, size| generate_warehouse(rng, size, 200), |input| {
+            crate::assert_variants_agree(PART_ONE_VARIANTS, input, "part one");
+            crate::assert_variants_agree(PART_TWO_VARIANTS, input, "part two");
+        });
+    }
+}
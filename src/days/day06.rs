@@ -0,0 +1,77 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::helpers::generate::Rng;
+
+fn find_n_distinct_index(s: &str, n: usize) -> u32 {
+	let mut marker_queue = VecDeque::new();
+	let mut marker_index = 0;
+	for (index, c) in s.chars().enumerate() {
+		if marker_queue.len() == n {
+			marker_queue.pop_front();
+		}
+		marker_queue.push_back(c);
+		if marker_queue.len() == n {
+			let mut marker_set = HashSet::new();
+			for c in marker_queue.iter() {
+				marker_set.insert(c);
+			}
+			if marker_set.len() == n {
+				marker_index = index;
+				break;
+			}
+		}
+	}
+
+	marker_index as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    Some(find_n_distinct_index(input, 4) + 1)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    Some(find_n_distinct_index(input, 14) + 1)
+}
+
+/// A random signal over a small alphabet, ending with 14 distinct characters so both markers exist.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let letters: Vec<char> = ('a'..='z').collect();
+	let alphabet = &letters[..rng.range(3, 20) as usize];
+	let mut signal: String = (0..size * 20).map(|_| *rng.pick(alphabet)).collect();
+
+	let mut tail = letters[..14].to_vec();
+	rng.shuffle(&mut tail);
+	signal.extend(tail);
+	signal + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 6,
+        example: (Some(7), Some(19)),
+    }
+
+    #[test]
+    fn test_generated() {
+        fn first_marker(signal: &[char], length: usize) -> u32 {
+            let start = signal
+                .windows(length)
+                .position(|window| {
+                    let letters = window.iter().fold(0_u32, |set, c| set | 1 << (*c as u8 - b'a'));
+                    letters.count_ones() as usize == length
+                })
+                .unwrap();
+            (start + length) as u32
+        }
+
+        generate::check(500, 25, generate, |input| {
+            let signal: Vec<char> = input.trim_end().chars().collect();
+            assert_eq!(part_one(input), Some(first_marker(&signal, 4)));
+            assert_eq!(part_two(input), Some(first_marker(&signal, 14)));
+        });
+    }
+}
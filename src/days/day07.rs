@@ -0,0 +1,993 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::helpers::generate::Rng;
+use crate::helpers::parse::ParseError;
+use crate::params::{Param, Params};
+
+pub const PARAMS: &[Param] = &[
+	Param::new("small_dir_limit", 100_000, 100_000),
+	Param::new("disk_size", 70_000_000, 70_000_000),
+	Param::new("needed_space", 30_000_000, 30_000_000),
+];
+
+/// Index of a node in the arena of a [`FileSystem`].
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directory {
+	pub name: String,
+	/// `None` for the root directory.
+	pub parent: Option<NodeId>,
+	/// Subdirectories and files, in the order they were first listed.
+	pub children: Vec<NodeId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+	pub name: String,
+	pub parent: NodeId,
+	pub size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+	Directory(Directory),
+	File(File),
+}
+
+impl Node {
+	pub fn name(&self) -> &str {
+		match self {
+			Node::Directory(directory) => &directory.name,
+			Node::File(file) => &file.name,
+		}
+	}
+}
+
+/// The filesystem explored by a terminal transcript.
+/// Nodes live in an arena and are also indexed by their absolute path, e.g. `/a/e/i`.
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+	nodes: Vec<Node>,
+	paths: HashMap<String, NodeId>,
+	/// Total size per node, computed once after parsing.
+	sizes: Vec<u32>,
+}
+
+/// Two filesystems are equal if they have the same paths with the same sizes,
+/// no matter in which order their entries were listed.
+impl PartialEq for FileSystem {
+	fn eq(&self, other: &Self) -> bool {
+		self.paths.len() == other.paths.len()
+			&& self.paths.iter().all(|(path, id)| {
+				other.lookup(path).is_some_and(|other_id| {
+					matches!(
+						(&self.nodes[*id], &other.nodes[other_id]),
+						(Node::Directory(_), Node::Directory(_)) | (Node::File(_), Node::File(_))
+					) && self.size(*id) == other.size(other_id)
+				})
+			})
+	}
+}
+
+impl Eq for FileSystem {}
+
+impl Default for FileSystem {
+	fn default() -> Self {
+		FileSystem::new()
+	}
+}
+
+impl FileSystem {
+	pub const ROOT: NodeId = 0;
+
+	/// A filesystem with an empty root directory.
+	pub fn new() -> Self {
+		let root = Node::Directory(Directory { name: "/".to_string(), parent: None, children: Vec::new() });
+		FileSystem { nodes: vec![root], paths: HashMap::from([("/".to_string(), FileSystem::ROOT)]), sizes: vec![0] }
+	}
+
+	/// Replays a transcript of `cd` and `ls` commands.
+	/// The transcript starts in the root directory. `cd ..` in the root stays there, like in a real shell,
+	/// and listing a directory again only adds entries that were not listed before.
+	pub fn parse(input: &str) -> Result<Self, ParseError> {
+		let mut file_system = FileSystem::new();
+		let mut current = FileSystem::ROOT;
+		let mut listing = false;
+
+		for (index, line) in input.lines().enumerate() {
+			let line = line.trim_end();
+			if line.is_empty() {
+				continue;
+			}
+			file_system
+				.apply_line(line, &mut current, &mut listing)
+				.map_err(|e| e.at_line(index + 1))?;
+		}
+		file_system.compute_sizes();
+		Ok(file_system)
+	}
+
+	/// Sums up the sizes bottom-up. Children are always added after their parent,
+	/// so visiting the nodes backwards handles every child before its parent.
+	fn compute_sizes(&mut self) {
+		self.sizes = self.nodes.iter().map(|node| match node {
+			Node::File(file) => file.size,
+			Node::Directory(_) => 0,
+		}).collect();
+		for id in (1..self.nodes.len()).rev() {
+			if let Some(parent) = self.parent(id) {
+				self.sizes[parent] += self.sizes[id];
+			}
+		}
+	}
+
+	fn apply_line(&mut self, line: &str, current: &mut NodeId, listing: &mut bool) -> Result<(), ParseError> {
+		if let Some(command) = line.strip_prefix('$') {
+			*listing = false;
+			match command.split_whitespace().collect::<Vec<_>>()[..] {
+				["cd", "/"] => *current = FileSystem::ROOT,
+				["cd", ".."] => *current = self.parent(*current).unwrap_or(FileSystem::ROOT),
+				["cd", name] => *current = self.add_directory(*current, name)?,
+				["ls"] => *listing = true,
+				_ => return Err(ParseError::new(format!("unknown command `{}`", command.trim()))),
+			}
+			return Ok(());
+		}
+
+		if !*listing {
+			return Err(ParseError::new(format!("`{}` is not the output of `ls`", line)));
+		}
+		match line.split_whitespace().collect::<Vec<_>>()[..] {
+			["dir", name] => {
+				self.add_directory(*current, name)?;
+			}
+			[size, name] => {
+				let size = size
+					.parse()
+					.map_err(|e| ParseError::new(format!("invalid file size `{}`: {}", size, e)))?;
+				self.add_file(*current, name, size)?;
+			}
+			_ => return Err(ParseError::new(format!("invalid `ls` output `{}`", line))),
+		}
+		Ok(())
+	}
+
+	pub fn node(&self, id: NodeId) -> &Node {
+		&self.nodes[id]
+	}
+
+	pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+		match &self.nodes[id] {
+			Node::Directory(directory) => directory.parent,
+			Node::File(file) => Some(file.parent),
+		}
+	}
+
+	pub fn children(&self, id: NodeId) -> &[NodeId] {
+		match &self.nodes[id] {
+			Node::Directory(directory) => &directory.children,
+			Node::File(_) => &[],
+		}
+	}
+
+	/// The node at an absolute path such as `/a/e`.
+	pub fn lookup(&self, path: &str) -> Option<NodeId> {
+		self.paths.get(path).copied()
+	}
+
+	pub fn path(&self, id: NodeId) -> String {
+		match self.parent(id) {
+			None => "/".to_string(),
+			Some(FileSystem::ROOT) => format!("/{}", self.nodes[id].name()),
+			Some(parent) => format!("{}/{}", self.path(parent), self.nodes[id].name()),
+		}
+	}
+
+	fn child_path(&self, parent: NodeId, name: &str) -> Result<String, ParseError> {
+		if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+			return Err(ParseError::new(format!("invalid name `{}`", name)));
+		}
+		Ok(match parent {
+			FileSystem::ROOT => format!("/{}", name),
+			_ => format!("{}/{}", self.path(parent), name),
+		})
+	}
+
+	fn insert(&mut self, parent: NodeId, path: String, node: Node) -> NodeId {
+		let id = self.nodes.len();
+		if let Node::Directory(directory) = &mut self.nodes[parent] {
+			directory.children.push(id);
+		}
+		self.nodes.push(node);
+		self.paths.insert(path, id);
+		id
+	}
+
+	/// Adds a subdirectory, or returns the existing one with that name.
+	fn add_directory(&mut self, parent: NodeId, name: &str) -> Result<NodeId, ParseError> {
+		let path = self.child_path(parent, name)?;
+		match self.lookup(&path) {
+			Some(id) => match &self.nodes[id] {
+				Node::Directory(_) => Ok(id),
+				Node::File(_) => Err(ParseError::new(format!("`{}` is a file, not a directory", path))),
+			},
+			None => Ok(self.insert(parent, path, Node::Directory(Directory { name: name.to_string(), parent: Some(parent), children: Vec::new() }))),
+		}
+	}
+
+	/// Adds a file. Listing the same file again is fine, as long as its size did not change.
+	fn add_file(&mut self, parent: NodeId, name: &str, size: u32) -> Result<NodeId, ParseError> {
+		let path = self.child_path(parent, name)?;
+		match self.lookup(&path) {
+			Some(id) => match &self.nodes[id] {
+				Node::File(file) if file.size == size => Ok(id),
+				Node::File(file) => Err(ParseError::new(format!("`{}` was listed with size {} before, now {}", path, file.size, size))),
+				Node::Directory(_) => Err(ParseError::new(format!("`{}` is a directory, not a file", path))),
+			},
+			None => Ok(self.insert(parent, path, Node::File(File { name: name.to_string(), parent, size }))),
+		}
+	}
+
+	/// All directories, including the root.
+	pub fn directories(&self) -> impl Iterator<Item = NodeId> + '_ {
+		(0..self.nodes.len()).filter(|id| matches!(self.nodes[*id], Node::Directory(_)))
+	}
+
+	/// The total size of a file or of everything inside a directory.
+	pub fn size(&self, id: NodeId) -> u32 {
+		self.sizes[id]
+	}
+
+	/// All directories with their sizes, like `du`.
+	pub fn directory_sizes(&self) -> impl Iterator<Item = (NodeId, u32)> + '_ {
+		self.directories().map(|id| (id, self.size(id)))
+	}
+
+	/// The directories whose size matches a predicate, e.g. `|size| size <= 100_000`.
+	pub fn find_directories(&self, predicate: impl Fn(u32) -> bool) -> Vec<NodeId> {
+		self.directories().filter(|id| predicate(self.size(*id))).collect()
+	}
+
+	/// The `count` largest directories, largest first.
+	pub fn largest_directories(&self, count: usize) -> Vec<NodeId> {
+		let mut directories: Vec<NodeId> = self.directories().collect();
+		directories.sort_by_key(|id| (std::cmp::Reverse(self.size(*id)), *id));
+		directories.truncate(count);
+		directories
+	}
+
+	/// The directories whose deletion frees enough space, smallest first.
+	/// Empty if even deleting everything is not enough.
+	pub fn cleanup_candidates(&self, disk_size: u32, needed_space: u32) -> Vec<NodeId> {
+		if needed_space > disk_size {
+			return Vec::new();
+		}
+		let min_delete_size = calculate_min_required_delete_size(self.size(FileSystem::ROOT), disk_size, needed_space);
+		let mut candidates = self.find_directories(|size| size >= min_delete_size);
+		candidates.sort_by_key(|id| (self.size(*id), *id));
+		candidates
+	}
+
+	/// The files whose name matches a glob pattern with `*` and `?`, like `find -name`.
+	pub fn find_files(&self, pattern: &str) -> Vec<NodeId> {
+		(0..self.nodes.len())
+			.filter(|id| matches!(&self.nodes[*id], Node::File(file) if glob_match(pattern, &file.name)))
+			.collect()
+	}
+
+	/// A `du -h` style report of a directory and its subdirectories up to `max_depth` levels below it,
+	/// children before their parent.
+	pub fn du(&self, from: NodeId, max_depth: Option<usize>) -> String {
+		let mut lines = Vec::new();
+		self.du_lines(from, 0, max_depth, &mut lines);
+		lines.join("\n")
+	}
+
+	fn du_lines(&self, id: NodeId, depth: usize, max_depth: Option<usize>, lines: &mut Vec<String>) {
+		for child in self.children(id) {
+			if matches!(self.nodes[*child], Node::Directory(_)) && max_depth.is_none_or(|max| depth < max) {
+				self.du_lines(*child, depth + 1, max_depth, lines);
+			}
+		}
+		lines.push(format!("{}\t{}", human_size(self.size(id)), self.path(id)));
+	}
+
+	/// A node as `ls` prints it, e.g. `dir a` or `14848514 b.txt`.
+	fn entry(&self, id: NodeId) -> String {
+		match &self.nodes[id] {
+			Node::Directory(directory) => format!("dir {}", directory.name),
+			Node::File(file) => format!("{} {}", file.size, file.name),
+		}
+	}
+
+	/// The output of `ls` in a directory.
+	fn listing(&self, id: NodeId) -> Vec<String> {
+		self.children(id).iter().map(|child| self.entry(*child)).collect()
+	}
+
+	/// The node and its ancestors, starting at the root.
+	fn ancestors(&self, mut id: NodeId) -> Vec<NodeId> {
+		let mut ancestors = vec![id];
+		while let Some(parent) = self.parent(id) {
+			ancestors.push(parent);
+			id = parent;
+		}
+		ancestors.reverse();
+		ancestors
+	}
+
+	/// A transcript of `cd` and `ls` commands that explores the whole filesystem, the inverse of [`FileSystem::parse`].
+	pub fn transcript(&self, traversal: Traversal) -> String {
+		let mut transcript = Transcript { file_system: self, output: String::from("$ cd /\n"), current: FileSystem::ROOT };
+		match traversal {
+			Traversal::DepthFirst => transcript.depth_first(FileSystem::ROOT),
+			Traversal::BreadthFirst => {
+				let mut queue = VecDeque::from([FileSystem::ROOT]);
+				while let Some(id) = queue.pop_front() {
+					transcript.cd(id);
+					transcript.ls(self.listing(id));
+					queue.extend(self.children(id).iter().filter(|child| matches!(self.nodes[**child], Node::Directory(_))));
+				}
+			}
+			Traversal::Random { seed } => transcript.random(&mut Rng::new(seed)),
+		}
+		transcript.output
+	}
+
+	/// Draws a directory and everything below it like `tree`, with the size of every node.
+	/// Levels deeper than `max_depth` below `from` are left out.
+	pub fn render_tree(&self, from: NodeId, order: TreeOrder, max_depth: Option<usize>) -> String {
+		let mut output = self.tree_label(from);
+		self.render_children(from, order, max_depth, "", &mut output);
+		output
+	}
+
+	fn render_children(&self, id: NodeId, order: TreeOrder, max_depth: Option<usize>, prefix: &str, output: &mut String) {
+		if max_depth == Some(0) {
+			return;
+		}
+		let mut children = self.children(id).to_vec();
+		match order {
+			TreeOrder::Listing => {}
+			TreeOrder::Name => children.sort_by(|a, b| self.nodes[*a].name().cmp(self.nodes[*b].name())),
+			TreeOrder::Size => children.sort_by_key(|child| std::cmp::Reverse(self.size(*child))),
+		}
+
+		for (index, child) in children.iter().enumerate() {
+			let last = index + 1 == children.len();
+			output.push_str(&format!("\n{}{}{}", prefix, if last { "└── " } else { "├── " }, self.tree_label(*child)));
+			let prefix = format!("{}{}", prefix, if last { "    " } else { "│   " });
+			self.render_children(*child, order, max_depth.map(|depth| depth - 1), &prefix, output);
+		}
+	}
+
+	fn tree_label(&self, id: NodeId) -> String {
+		match &self.nodes[id] {
+			Node::Directory(directory) if id == FileSystem::ROOT => format!("{} ({})", directory.name, self.size(id)),
+			Node::Directory(directory) => format!("{}/ ({})", directory.name, self.size(id)),
+			Node::File(file) => format!("{} ({})", file.name, file.size),
+		}
+	}
+
+	/// The subtree below a node as a nested structure, e.g. for JSON export.
+	pub fn to_tree(&self, id: NodeId) -> TreeNode {
+		match &self.nodes[id] {
+			Node::Directory(directory) => TreeNode::Directory {
+				name: directory.name.clone(),
+				size: self.size(id),
+				children: directory.children.iter().map(|child| self.to_tree(*child)).collect(),
+			},
+			Node::File(file) => TreeNode::File { name: file.name.clone(), size: file.size },
+		}
+	}
+
+	/// Rebuilds a filesystem from the tree of its root directory.
+	/// Directory sizes in the tree are ignored and computed again from the files.
+	pub fn from_tree(tree: &TreeNode) -> Result<Self, ParseError> {
+		let TreeNode::Directory { children, .. } = tree else {
+			return Err(ParseError::new("the root must be a directory"));
+		};
+		let mut file_system = FileSystem::new();
+		for child in children {
+			file_system.add_tree(FileSystem::ROOT, child)?;
+		}
+		file_system.compute_sizes();
+		Ok(file_system)
+	}
+
+	fn add_tree(&mut self, parent: NodeId, tree: &TreeNode) -> Result<(), ParseError> {
+		match tree {
+			TreeNode::Directory { name, children, .. } => {
+				let id = self.add_directory(parent, name)?;
+				for child in children {
+					self.add_tree(id, child)?;
+				}
+			}
+			TreeNode::File { name, size } => {
+				self.add_file(parent, name, *size)?;
+			}
+		}
+		Ok(())
+	}
+
+	pub fn to_json(&self) -> String {
+		serde_json::to_string_pretty(&self.to_tree(FileSystem::ROOT)).expect("trees are always serializable")
+	}
+
+	pub fn from_json(json: &str) -> Result<Self, ParseError> {
+		let tree: TreeNode = serde_json::from_str(json).map_err(|e| ParseError::new(format!("invalid JSON: {}", e)).at_line(e.line()))?;
+		FileSystem::from_tree(&tree)
+	}
+}
+
+/// The order in which [`FileSystem::transcript`] visits the directories.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Traversal {
+	/// Like the puzzle input: list a directory, then `cd` into each subdirectory and back out.
+	DepthFirst,
+	/// Level by level, moving between directories through their closest common ancestor.
+	BreadthFirst,
+	/// Directories in random order, with detours through `cd /` and `cd ..`,
+	/// shuffled listings and directories that are listed twice.
+	Random { seed: u64 },
+}
+
+struct Transcript<'a> {
+	file_system: &'a FileSystem,
+	output: String,
+	current: NodeId,
+}
+
+impl Transcript<'_> {
+	fn command(&mut self, command: &str) {
+		self.output.push_str(&format!("$ {}\n", command));
+	}
+
+	/// Moves to a directory by going up to the closest common ancestor and down from there.
+	fn cd(&mut self, target: NodeId) {
+		let file_system = self.file_system;
+		let path = file_system.ancestors(target);
+		while !path.contains(&self.current) {
+			self.command("cd ..");
+			self.current = file_system.parent(self.current).unwrap();
+		}
+		let start = path.iter().position(|id| *id == self.current).unwrap();
+		for id in &path[start + 1..] {
+			self.command(&format!("cd {}", file_system.node(*id).name()));
+		}
+		self.current = target;
+	}
+
+	fn ls(&mut self, entries: Vec<String>) {
+		self.command("ls");
+		for entry in entries {
+			self.output.push_str(&entry);
+			self.output.push('\n');
+		}
+	}
+
+	fn depth_first(&mut self, id: NodeId) {
+		let file_system = self.file_system;
+		self.ls(file_system.listing(id));
+		for child in file_system.children(id) {
+			if let Node::Directory(directory) = file_system.node(*child) {
+				self.command(&format!("cd {}", directory.name));
+				self.current = *child;
+				self.depth_first(*child);
+				self.command("cd ..");
+				self.current = id;
+			}
+		}
+	}
+
+	fn random(&mut self, rng: &mut Rng) {
+		let file_system = self.file_system;
+		let mut directories: Vec<NodeId> = file_system.directories().collect();
+		rng.shuffle(&mut directories);
+
+		for id in directories {
+			if rng.chance(0.2) {
+				self.command("cd /");
+				self.current = FileSystem::ROOT;
+			}
+			if rng.chance(0.2) {
+				// a pointless step up and back, which stays in place in the root.
+				let current = self.current;
+				self.command("cd ..");
+				self.current = file_system.parent(current).unwrap_or(FileSystem::ROOT);
+				self.cd(current);
+			}
+			self.cd(id);
+
+			let listings = if rng.chance(0.3) { 2 } else { 1 };
+			for _ in 0..listings {
+				let mut entries = file_system.listing(id);
+				rng.shuffle(&mut entries);
+				self.ls(entries);
+			}
+		}
+	}
+}
+
+/// How `tree` orders the entries of a directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TreeOrder {
+	/// In the order they were first listed.
+	Listing,
+	Name,
+	/// Largest first.
+	Size,
+}
+
+impl FromStr for TreeOrder {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"listing" => Ok(TreeOrder::Listing),
+			"name" => Ok(TreeOrder::Name),
+			"size" => Ok(TreeOrder::Size),
+			_ => Err(format!("unknown order `{}`, use listing, name or size", s)),
+		}
+	}
+}
+
+/// A node with everything below it, as exported to JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TreeNode {
+	Directory {
+		name: String,
+		#[serde(default)]
+		size: u32,
+		children: Vec<TreeNode>,
+	},
+	File {
+		name: String,
+		size: u32,
+	},
+}
+
+/// Sizes in the style of `du -h`: `512`, `1.5K`, `23M`.
+pub fn human_size(size: u32) -> String {
+	let mut value = size as f64;
+	for unit in ["", "K", "M", "G"] {
+		if value < 1024.0 || unit == "G" {
+			return match unit {
+				"" => format!("{}", size),
+				_ if value < 10.0 => format!("{:.1}{}", value, unit),
+				_ => format!("{:.0}{}", value, unit),
+			};
+		}
+		value /= 1024.0;
+	}
+	unreachable!()
+}
+
+/// Matches a whole name against a pattern where `*` matches any text and `?` any single character.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+	let pattern: Vec<char> = pattern.chars().collect();
+	let name: Vec<char> = name.chars().collect();
+	// matches[j]: whether the pattern so far matches the first j characters of the name.
+	let mut matches = vec![false; name.len() + 1];
+	matches[0] = true;
+	for token in pattern {
+		matches = match token {
+			'*' => {
+				let first = matches.iter().position(|matched| *matched);
+				(0..=name.len()).map(|j| first.is_some_and(|first| j >= first)).collect()
+			}
+			_ => (0..=name.len())
+				.map(|j| j > 0 && matches[j - 1] && (token == '?' || token == name[j - 1]))
+				.collect(),
+		};
+	}
+	matches[name.len()]
+}
+
+/// How much has to be deleted so that `needed_space` of `disk_size` is free.
+fn calculate_min_required_delete_size(used_space: u32, disk_size: u32, needed_space: u32) -> u32 {
+	let max_allowed_size = disk_size.saturating_sub(needed_space);
+
+	used_space.saturating_sub(max_allowed_size)
+}
+
+pub fn part_one(input: &str, params: &Params) -> Option<u32> {
+	let file_system = FileSystem::parse(input).unwrap();
+	let limit: u32 = params.get("small_dir_limit");
+
+	Some(file_system.find_directories(|size| size <= limit).iter().map(|id| file_system.size(*id)).sum())
+}
+
+pub fn part_two(input: &str, params: &Params) -> Option<u32> {
+	let file_system = FileSystem::parse(input).unwrap();
+
+	// the root directory is always big enough.
+	let candidates = file_system.cleanup_candidates(params.get("disk_size"), params.get("needed_space"));
+	candidates.first().map(|id| file_system.size(*id))
+}
+
+pub const SHELL_HELP: &str = "commands: cd <path>, ls [path], pwd, du [-d <depth>] [path], find <pattern>, cleanup [<disk size> <needed space>], help, exit";
+
+/// An interactive shell over a parsed filesystem.
+pub struct Shell<'a> {
+	file_system: &'a FileSystem,
+	current: NodeId,
+	params: &'a Params,
+}
+
+impl<'a> Shell<'a> {
+	/// A shell in the root directory. `cleanup` without arguments uses the disk size and needed space of `params`.
+	pub fn new(file_system: &'a FileSystem, params: &'a Params) -> Self {
+		Shell { file_system, current: FileSystem::ROOT, params }
+	}
+
+	pub fn pwd(&self) -> String {
+		self.file_system.path(self.current)
+	}
+
+	/// Runs one command and returns its output.
+	pub fn execute(&mut self, line: &str) -> Result<String, String> {
+		let file_system = self.file_system;
+		match line.split_whitespace().collect::<Vec<_>>()[..] {
+			[] => Ok(String::new()),
+			["help"] => Ok(SHELL_HELP.to_string()),
+			["pwd"] => Ok(self.pwd()),
+			["cd"] => {
+				self.current = FileSystem::ROOT;
+				Ok(String::new())
+			}
+			["cd", path] => {
+				self.current = self.directory(path)?;
+				Ok(String::new())
+			}
+			["ls"] => Ok(self.ls(self.current)),
+			["ls", path] => Ok(self.ls(self.resolve(path)?)),
+			["du", ref args @ ..] => {
+				let (depth, path) = match args {
+					["-d", depth, rest @ ..] => (Some(depth.parse().map_err(|_| format!("invalid depth `{}`", depth))?), rest),
+					rest => (None, rest),
+				};
+				let from = match path {
+					[] => self.current,
+					[path] => self.directory(path)?,
+					_ => return Err("usage: du [-d <depth>] [path]".to_string()),
+				};
+				Ok(file_system.du(from, depth))
+			}
+			["find", pattern] => Ok(file_system
+				.find_files(pattern)
+				.into_iter()
+				.filter(|id| self.is_below(*id, self.current))
+				.map(|id| file_system.path(id))
+				.collect::<Vec<_>>()
+				.join("\n")),
+			["cleanup", ref args @ ..] => {
+				let (disk_size, needed_space) = match args {
+					[] => (self.params.get("disk_size"), self.params.get("needed_space")),
+					[disk_size, needed_space] => (parse_size(disk_size)?, parse_size(needed_space)?),
+					_ => return Err("usage: cleanup [<disk size> <needed space>]".to_string()),
+				};
+				Ok(self.cleanup(disk_size, needed_space))
+			}
+			[command, ..] => Err(format!("unknown command `{}`, try `help`", command)),
+		}
+	}
+
+	/// Resolves an absolute or relative path with `.` and `..` components.
+	fn resolve(&self, path: &str) -> Result<NodeId, String> {
+		let mut id = if path.starts_with('/') { FileSystem::ROOT } else { self.current };
+		for component in path.split('/').filter(|component| !component.is_empty()) {
+			id = match component {
+				"." => id,
+				".." => self.file_system.parent(id).unwrap_or(FileSystem::ROOT),
+				name => *self
+					.file_system
+					.children(id)
+					.iter()
+					.find(|child| self.file_system.node(**child).name() == name)
+					.ok_or_else(|| format!("`{}`: no such file or directory", path))?,
+			};
+		}
+		Ok(id)
+	}
+
+	fn directory(&self, path: &str) -> Result<NodeId, String> {
+		let id = self.resolve(path)?;
+		match self.file_system.node(id) {
+			Node::Directory(_) => Ok(id),
+			Node::File(_) => Err(format!("`{}` is not a directory", path)),
+		}
+	}
+
+	fn ls(&self, id: NodeId) -> String {
+		match self.file_system.node(id) {
+			Node::Directory(_) => self.file_system.listing(id).join("\n"),
+			Node::File(_) => self.file_system.entry(id),
+		}
+	}
+
+	fn is_below(&self, mut id: NodeId, directory: NodeId) -> bool {
+		while id != directory {
+			match self.file_system.parent(id) {
+				Some(parent) => id = parent,
+				None => return false,
+			}
+		}
+		true
+	}
+
+	fn cleanup(&self, disk_size: u32, needed_space: u32) -> String {
+		let file_system = self.file_system;
+		let used_space = file_system.size(FileSystem::ROOT);
+		let missing = calculate_min_required_delete_size(used_space, disk_size, needed_space);
+		if missing == 0 {
+			return format!("{} of {} used, {} are already free.", used_space, disk_size, disk_size.saturating_sub(used_space));
+		}
+		let candidates = file_system.cleanup_candidates(disk_size, needed_space);
+		if candidates.is_empty() {
+			return format!("{} of {} used, no directory frees {}.", used_space, disk_size, missing);
+		}
+		let mut lines = vec![format!("{} of {} used, delete at least {}:", used_space, disk_size, missing)];
+		lines.extend(candidates.iter().map(|id| format!("{}\t{}", file_system.size(*id), file_system.path(*id))));
+		lines.join("\n")
+	}
+}
+
+fn parse_size(size: &str) -> Result<u32, String> {
+	size.parse().map_err(|_| format!("invalid size `{}`", size))
+}
+
+/// A random directory tree, explored depth-first with `cd` and `ls`.
+/// `size` is the number of directories.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut output = String::from("$ cd /\n");
+	let mut remaining_directories = size.saturating_sub(1);
+	generate_directory(rng, &mut remaining_directories, &mut output);
+	output
+}
+
+fn generate_directory(rng: &mut Rng, remaining_directories: &mut usize, output: &mut String) {
+	let directory_count = rng.range(0, 3).min(*remaining_directories as i64) as usize;
+	*remaining_directories -= directory_count;
+
+	let directories: Vec<String> = (0..directory_count).map(|index| format!("d{}{}", index, (b'a' + rng.below(26) as u8) as char)).collect();
+	let mut entries: Vec<String> = directories.iter().map(|name| format!("dir {}", name)).collect();
+	for index in 0..rng.range(0, 4) {
+		// mostly small files, so that some directories stay below the size limit of part one.
+		let size = if rng.chance(0.5) { rng.range(1, 30_000) } else { rng.range(1, 3_000_000) };
+		entries.push(format!("{} f{}.txt", size, index));
+	}
+	rng.shuffle(&mut entries);
+
+	output.push_str("$ ls\n");
+	for entry in entries {
+		output.push_str(&entry);
+		output.push('\n');
+	}
+
+	for name in directories {
+		output.push_str(&format!("$ cd {}\n", name));
+		generate_directory(rng, remaining_directories, output);
+		output.push_str("$ cd ..\n");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 7,
+        params: PARAMS,
+        example: (Some(95437), Some(24933642)),
+    }
+
+    #[test]
+    fn test_parse_file_system() {
+        let file_system = FileSystem::parse("$ ls\ndir a\n10 b.txt\n$ cd a\n$ ls\n20 c\n").unwrap();
+        assert_eq!(file_system.size(FileSystem::ROOT), 30);
+        assert_eq!(file_system.lookup("/a/c").map(|id| file_system.size(id)), Some(20));
+        assert_eq!(file_system.path(file_system.lookup("/a/c").unwrap()), "/a/c");
+    }
+
+    #[test]
+    fn test_cd_root_and_repeated_listings() {
+        let input = "$ cd /\n$ cd ..\n$ ls\ndir a\n$ cd a\n$ ls\n5 x\n$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n5 x\n7 y\n";
+        let file_system = FileSystem::parse(input).unwrap();
+        let a = file_system.lookup("/a").unwrap();
+        assert_eq!(file_system.children(FileSystem::ROOT), &[a]);
+        assert_eq!(file_system.children(a).len(), 2);
+        assert_eq!(file_system.size(FileSystem::ROOT), 12);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input: &str| FileSystem::parse(input).unwrap_err();
+
+        assert_eq!(error("$ ls\n5 x\n$ rm x\n").line(), Some(3));
+        assert_eq!(error("$ cd a\n5 x\n").line(), Some(2));
+        assert_eq!(error("$ ls\nabc x\n").line(), Some(2));
+        assert_eq!(error("$ ls\n5 x\n$ ls\n6 x\n").line(), Some(4));
+        assert_eq!(error("$ ls\n5 x\n$ cd x\n").line(), Some(3));
+        assert_eq!(error("$ ls\ndir a\n1 2 3\n").line(), Some(3));
+    }
+
+    #[test]
+    fn test_queries() {
+        let file_system = FileSystem::parse(&crate::read_file("examples", 7)).unwrap();
+        let paths = |ids: Vec<NodeId>| ids.into_iter().map(|id| file_system.path(id)).collect::<Vec<_>>();
+
+        assert_eq!(file_system.directory_sizes().count(), 4);
+        assert_eq!(paths(file_system.find_directories(|size| size <= 100_000)), ["/a", "/a/e"]);
+        assert_eq!(paths(file_system.largest_directories(2)), ["/", "/d"]);
+        assert_eq!(paths(file_system.find_files("*.*")), ["/b.txt", "/c.dat", "/a/h.lst", "/d/d.log", "/d/d.ext"]);
+        assert_eq!(paths(file_system.find_files("?")), ["/a/f", "/a/g", "/a/e/i", "/d/j", "/d/k"]);
+        assert_eq!(
+            file_system.du(FileSystem::ROOT, Some(1)),
+            "93K\t/a\n24M\t/d\n46M\t/"
+        );
+    }
+
+    #[test]
+    fn test_glob_and_human_size() {
+        assert!(glob_match("*.txt", "b.txt"));
+        assert!(glob_match("d.*", "d.log"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("?", "ab"));
+        assert!(glob_match("a*b*c", "aXbYbc"));
+        assert!(!glob_match("a*b", "ab.c"));
+
+        assert_eq!(human_size(512), "512");
+        assert_eq!(human_size(1536), "1.5K");
+        assert_eq!(human_size(24_933_642), "24M");
+    }
+
+    #[test]
+    fn test_render_tree() {
+        let file_system = FileSystem::parse(&crate::read_file("examples", 7)).unwrap();
+        assert_eq!(
+            file_system.render_tree(FileSystem::ROOT, TreeOrder::Size, Some(1)),
+            ["/ (48381165)", "├── d/ (24933642)", "├── b.txt (14848514)", "├── c.dat (8504156)", "└── a/ (94853)"].join("\n")
+        );
+        assert_eq!(
+            file_system.render_tree(file_system.lookup("/a").unwrap(), TreeOrder::Name, None),
+            ["a/ (94853)", "├── e/ (584)", "│   └── i (584)", "├── f (29116)", "├── g (2557)", "└── h.lst (62596)"].join("\n")
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let file_system = FileSystem::parse(&crate::read_file("examples", 7)).unwrap();
+        let json = file_system.to_json();
+        assert!(json.contains("\"type\": \"directory\""));
+        assert_eq!(FileSystem::from_json(&json).unwrap(), file_system);
+
+        generate::check(100, 20, generate, |input| {
+            let file_system = FileSystem::parse(input).unwrap();
+            assert_eq!(FileSystem::from_json(&file_system.to_json()).unwrap(), file_system);
+        });
+
+        assert!(FileSystem::from_json(r#"{"type": "file", "name": "a", "size": 1}"#).is_err());
+        assert_eq!(FileSystem::from_json("{\n  \"type\": 1\n}").unwrap_err().line(), Some(2));
+    }
+
+    #[test]
+    fn test_shell() {
+        let file_system = FileSystem::parse(&crate::read_file("examples", 7)).unwrap();
+        let params = Params::real(PARAMS);
+        let mut shell = Shell::new(&file_system, &params);
+
+        assert_eq!(shell.execute("pwd").unwrap(), "/");
+        assert_eq!(shell.execute("ls").unwrap(), "dir a\n14848514 b.txt\n8504156 c.dat\ndir d");
+        assert_eq!(shell.execute("cd a/e").unwrap(), "");
+        assert_eq!(shell.pwd(), "/a/e");
+        assert_eq!(shell.execute("ls ../../d/k").unwrap(), "7214296 k");
+        shell.execute("cd ..").unwrap();
+        assert_eq!(shell.execute("du").unwrap(), "584\t/a/e\n93K\t/a");
+        assert_eq!(shell.execute("du -d 0 /").unwrap(), "46M\t/");
+        assert_eq!(shell.execute("find ?").unwrap(), "/a/f\n/a/g\n/a/e/i");
+
+        assert!(shell.execute("cd b.txt").is_err());
+        assert!(shell.execute("cd /b.txt").is_err());
+        assert!(shell.execute("cd x").is_err());
+        assert!(shell.execute("rm -rf /").is_err());
+        assert_eq!(shell.pwd(), "/a");
+    }
+
+    #[test]
+    fn test_cleanup() {
+        let file_system = FileSystem::parse(&crate::read_file("examples", 7)).unwrap();
+        let paths = |ids: Vec<NodeId>| ids.into_iter().map(|id| file_system.path(id)).collect::<Vec<_>>();
+        assert_eq!(paths(file_system.cleanup_candidates(70_000_000, 30_000_000)), ["/d", "/"]);
+        assert_eq!(paths(file_system.cleanup_candidates(48_381_165, 500)), ["/a/e", "/a", "/d", "/"]);
+        assert!(file_system.cleanup_candidates(100, 200).is_empty());
+
+        let params = Params::real(PARAMS);
+        let mut shell = Shell::new(&file_system, &params);
+        assert_eq!(
+            shell.execute("cleanup").unwrap(),
+            "48381165 of 70000000 used, delete at least 8381165:\n24933642\t/d\n48381165\t/"
+        );
+        assert_eq!(shell.execute("cleanup 100000000 1").unwrap(), "48381165 of 100000000 used, 51618835 are already free.");
+        assert!(shell.execute("cleanup 1").is_err());
+    }
+
+    #[test]
+    fn test_transcript() {
+        let example = crate::read_file("examples", 7);
+        let file_system = FileSystem::parse(&example).unwrap();
+        // the example leaves out the final step back to the root.
+        assert_eq!(file_system.transcript(Traversal::DepthFirst), format!("{}\n$ cd ..\n", example.trim_end()));
+
+        assert_eq!(
+            file_system.transcript(Traversal::BreadthFirst),
+            [
+                "$ cd /", "$ ls", "dir a", "14848514 b.txt", "8504156 c.dat", "dir d",
+                "$ cd a", "$ ls", "dir e", "29116 f", "2557 g", "62596 h.lst",
+                "$ cd ..", "$ cd d", "$ ls", "4060174 j", "8033020 d.log", "5626152 d.ext", "7214296 k",
+                "$ cd ..", "$ cd a", "$ cd e", "$ ls", "584 i\n",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_transcript_round_trip() {
+        generate::check(300, 30, generate, |input| {
+            let file_system = FileSystem::parse(input).unwrap();
+            for traversal in [Traversal::DepthFirst, Traversal::BreadthFirst, Traversal::Random { seed: input.len() as u64 }] {
+                let transcript = file_system.transcript(traversal);
+                let parsed = FileSystem::parse(&transcript).unwrap();
+                assert_eq!(parsed, file_system, "{:?}:\n{}", traversal, transcript);
+                assert_eq!(parsed.size(FileSystem::ROOT), file_system.size(FileSystem::ROOT));
+                assert_eq!(part_two(&transcript, &Params::real(PARAMS)), part_two(input, &Params::real(PARAMS)));
+            }
+        });
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::HashMap;
+
+        generate::check(1000, 40, generate, |input| {
+            let mut sizes: HashMap<Vec<&str>, u32> = HashMap::from([(vec![], 0)]);
+            let mut path: Vec<&str> = Vec::new();
+            for line in input.lines() {
+                match line.split(' ').collect::<Vec<_>>()[..] {
+                    ["$", "cd", "/"] => path.clear(),
+                    ["$", "cd", ".."] => {
+                        path.pop();
+                    }
+                    ["$", "cd", name] => {
+                        path.push(name);
+                        sizes.entry(path.clone()).or_default();
+                    }
+                    ["$", "ls"] | ["dir", _] => {}
+                    [size, _] => {
+                        for depth in 0..=path.len() {
+                            *sizes.entry(path[..depth].to_vec()).or_default() += size.parse::<u32>().unwrap();
+                        }
+                    }
+                    _ => panic!("unexpected line `{}`", line),
+                }
+            }
+
+            let params = Params::real(PARAMS);
+            let limit: u32 = params.get("small_dir_limit");
+            let max_used = params.get::<u32>("disk_size") - params.get::<u32>("needed_space");
+            let missing = sizes[&vec![]].saturating_sub(max_used);
+
+            let small: u32 = sizes.values().filter(|size| **size <= limit).sum();
+            let smallest = sizes.values().filter(|size| **size >= missing).min().copied();
+
+            assert_eq!(part_one(input, &params), Some(small));
+            assert_eq!(part_two(input, &params), smallest);
+        });
+    }
+}
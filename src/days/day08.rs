@@ -0,0 +1,269 @@
+use crate::helpers::generate::Rng;
+use crate::runner::Variant;
+
+#[derive(Debug)]
+pub struct Forrest {
+	size: usize,
+	trees: Vec<Vec<u32>>,
+}
+
+impl From<&str> for Forrest {
+    fn from(s: &str) -> Self {
+        let trees: Vec<Vec<u32>> = s
+			.lines()
+			.map(|line| {
+				line.chars()
+					.map(|num| num.to_digit(10).unwrap())
+					.collect()
+			})
+			.collect();
+		let size = trees[0].len();
+		Forrest { size, trees }
+    }
+}
+
+impl Forrest {
+	/// The number of trees per side.
+	pub fn size(&self) -> usize {
+		self.size
+	}
+
+	/// The tree heights, row by row.
+	pub fn trees(&self) -> &[Vec<u32>] {
+		&self.trees
+	}
+
+	pub fn get_visible_tree_count(&self) -> u32 {
+		let mut visible_tree_count = 0;
+		for x in 0..self.size {
+			for y in 0..self.size {
+				if !self.is_view_blocked(x, y) {
+					visible_tree_count += 1;
+				}
+			}
+		}
+		visible_tree_count
+	}
+
+	fn is_view_blocked(&self, x: usize, y: usize) -> bool {
+		let tree_size = self.get_tree_size(x, y);
+
+		let mut left_blocked = false;
+		for x_pos in 0..x {
+			if self.get_tree_size(x_pos, y) >= tree_size {
+				left_blocked = true;
+				break;
+			}
+		}
+
+		let mut right_blocked = false;
+		for x_pos in x+1..self.size {
+			if self.get_tree_size(x_pos, y) >= tree_size {
+				right_blocked = true;
+				break;
+			}
+		}
+
+		let mut top_blocked = false;
+		for y_pos in 0..y {
+			if self.get_tree_size(x, y_pos) >= tree_size {
+				top_blocked = true;
+				break;
+			}
+		}
+
+		let mut bot_blocked = false;
+		for y_pos in y+1..self.size {
+			if self.get_tree_size(x, y_pos) >= tree_size {
+				bot_blocked = true;
+				break;
+			}
+		}
+
+		left_blocked && right_blocked && top_blocked && bot_blocked
+	}
+
+	fn get_highest_scenic_score(&self) -> u32 {
+		let mut highest_score = 0;
+		for x in 0..self.size {
+			for y in 0..self.size {
+				let score = self.get_scenic_score(x, y);
+				if score > highest_score {
+					highest_score = score;
+				}
+			}
+		}
+		highest_score
+	}
+
+	fn get_scenic_score(&self, x: usize, y: usize) -> u32 {
+		if x == 0 || x == self.size - 1 || y == 0 || y == self.size - 1 {
+			return 0;
+		}
+
+		let tree_size = self.get_tree_size(x, y);
+
+		let mut left_score = 0;
+		for x_pos in (0..x).rev() {
+			left_score += 1;
+			if self.get_tree_size(x_pos, y) >= tree_size {
+				break;
+			}
+		}
+
+		let mut right_score = 0;
+		for x_pos in x+1..self.size {
+			right_score += 1;
+			if self.get_tree_size(x_pos, y) >= tree_size {
+				break;
+			}
+		}
+
+		let mut top_score = 0;
+		for y_pos in (0..y).rev() {
+			top_score += 1;
+			if self.get_tree_size(x, y_pos) >= tree_size {
+				break;
+			}
+		}
+		
+		let mut bot_score = 0;
+		for y_pos in y+1..self.size {
+			bot_score += 1;
+			if self.get_tree_size(x, y_pos) >= tree_size {
+				break;
+			}
+		}
+
+		left_score * right_score * top_score * bot_score
+	}
+
+	fn get_tree_size(&self, x: usize, y: usize) -> u32 {
+		self.trees[y][x]
+	}
+
+	fn get_row(&self, y: usize) -> Vec<u32> {
+		self.trees[y].clone()
+	}
+
+	fn get_column(&self, x: usize) -> Vec<u32> {
+		self.trees.iter().map(|row| row[x]).collect()
+	}
+
+	/// Applies `score` to every row and column, looking from both ends,
+	/// and combines the four results of each tree with `combine`.
+	fn combine_lines<T: Copy>(&self, score: fn(&[u32]) -> Vec<T>, combine: fn(T, T) -> T) -> Vec<Vec<T>> {
+		let from_both_ends = |line: Vec<u32>| {
+			let forward = score(&line);
+			let mut reversed = line;
+			reversed.reverse();
+			let mut backward = score(&reversed);
+			backward.reverse();
+			forward.into_iter().zip(backward).map(|(a, b)| combine(a, b)).collect::<Vec<T>>()
+		};
+
+		let mut result: Vec<Vec<T>> = (0..self.size).map(|y| from_both_ends(self.get_row(y))).collect();
+		for x in 0..self.size {
+			for (row, value) in result.iter_mut().zip(from_both_ends(self.get_column(x))) {
+				row[x] = combine(row[x], value);
+			}
+		}
+		result
+	}
+
+	/// Linear version of `get_visible_tree_count`, using the highest tree seen so far per line.
+	fn get_visible_tree_count_fast(&self) -> u32 {
+		self.combine_lines(visible_from_start, |a, b| a || b)
+			.iter()
+			.flatten()
+			.filter(|visible| **visible)
+			.count() as u32
+	}
+
+	/// Linear version of `get_highest_scenic_score`, using a stack of the trees that can still block the view.
+	fn get_highest_scenic_score_fast(&self) -> u32 {
+		self.combine_lines(viewing_distances, |a, b| a * b)
+			.into_iter()
+			.flatten()
+			.max()
+			.unwrap_or(0)
+	}
+}
+
+fn visible_from_start(line: &[u32]) -> Vec<bool> {
+	let mut highest = None;
+	line.iter()
+		.map(|tree| {
+			let visible = highest.is_none_or(|highest| *tree > highest);
+			if visible {
+				highest = Some(*tree);
+			}
+			visible
+		})
+		.collect()
+}
+
+fn viewing_distances(line: &[u32]) -> Vec<u32> {
+	let mut blocking: Vec<usize> = Vec::new();
+	line.iter()
+		.enumerate()
+		.map(|(index, tree)| {
+			while blocking.last().is_some_and(|last| line[*last] < *tree) {
+				blocking.pop();
+			}
+			let distance = blocking.last().map_or(index, |last| index - last);
+			blocking.push(index);
+			distance as u32
+		})
+		.collect()
+}
+
+pub const PART_ONE_VARIANTS: &[Variant<str, u32>] = &[("fast", part_one), ("naive", part_one_naive)];
+pub const PART_TWO_VARIANTS: &[Variant<str, u32>] = &[("fast", part_two), ("naive", part_two_naive)];
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let forrest = Forrest::from(input);
+	Some(forrest.get_visible_tree_count_fast())
+}
+
+pub fn part_one_naive(input: &str) -> Option<u32> {
+    let forrest = Forrest::from(input);
+	Some(forrest.get_visible_tree_count())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let forrest = Forrest::from(input);
+	Some(forrest.get_highest_scenic_score_fast())
+}
+
+pub fn part_two_naive(input: &str) -> Option<u32> {
+    let forrest = Forrest::from(input);
+	Some(forrest.get_highest_scenic_score())
+}
+
+/// A random square forest with `size` trees per side.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| (0..size).map(|_| char::from(b'0' + rng.below(10) as u8)).collect::<String>() + "\n")
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 8,
+        variants: (PART_ONE_VARIANTS, PART_TWO_VARIANTS),
+        example: (Some(21), Some(8)),
+    }
+
+    #[test]
+    fn test_generated() {
+        generate::check(1000, 30, generate, |input| {
+            crate::assert_variants_agree(PART_ONE_VARIANTS, input, "part one");
+            crate::assert_variants_agree(PART_TWO_VARIANTS, input, "part two");
+        });
+    }
+}
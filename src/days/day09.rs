@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use crate::helpers::generate::Rng;
+use crate::helpers::parse::ParseError;
+use crate::helpers::viz::{Frame, Recorder, Rgb};
+use crate::scan;
+
+#[derive(PartialEq, Eq, Hash, Debug, Copy, Clone)]
+pub struct Point {
+	pub x: i32,
+	pub y: i32,
+}
+
+pub struct Rope {
+	head: Point,
+	other_points: Vec<Point>,
+	visited_tail_points: HashSet<Point>,
+}
+
+impl Rope {
+	pub fn new(length: u32) -> Rope {
+		let mut other_points = Vec::new();
+		for _ in 0..length {
+			other_points.push(Point { x: 0, y: 0 });
+		}
+
+		let mut visited_tail_points = HashSet::new();
+		visited_tail_points.insert(Point { x: 0, y: 0 });
+
+		Rope {
+			head: Point { x: 0, y: 0 },
+			other_points,
+			visited_tail_points,
+		}
+	}
+
+	pub fn move_head(&mut self, x_movement: i32, y_movement: i32) {
+		self.head.x += x_movement;
+		self.head.y += y_movement;
+
+		self.adjust_rope_positions();
+	}
+
+	fn adjust_rope_positions(&mut self) {
+		let mut last_point = self.head;
+		for point in self.other_points.iter_mut() {
+
+			Rope::adjust_next_point_position(last_point, point);
+			last_point = *point;
+		}
+		self.visited_tail_points.insert(last_point);
+	}
+
+	fn adjust_next_point_position(current_head: Point, next_point: &mut Point) {
+		let x_diff = current_head.x - next_point.x;
+		let y_diff = current_head.y - next_point.y;
+
+		if x_diff.abs() > 1 || y_diff.abs() > 1 {
+			next_point.x += x_diff.signum();
+			next_point.y += y_diff.signum();
+		}
+	}
+
+	pub fn head(&self) -> Point {
+		self.head
+	}
+
+	/// The knots behind the head, the last one is the tail.
+	pub fn knots(&self) -> &[Point] {
+		&self.other_points
+	}
+
+	pub fn visited_tail_points(&self) -> &HashSet<Point> {
+		&self.visited_tail_points
+	}
+
+	pub fn get_visited_tail_point_count(&self) -> usize {
+		self.visited_tail_points.len()
+	}
+
+	fn render(&self) -> Frame {
+		let points = self.visited_tail_points.iter().chain(self.other_points.iter()).chain([&self.head]);
+		let (min_x, max_x) = points.clone().fold((0, 0), |(min, max), p| (p.x.min(min), p.x.max(max)));
+		let (min_y, max_y) = points.fold((0, 0), |(min, max), p| (p.y.min(min), p.y.max(max)));
+
+		// y points up in the puzzle, so the frame is flipped vertically.
+		let mut frame = Frame::with_origin(
+			(min_x as i64 - 1, -(max_y as i64) - 1),
+			(max_x - min_x + 3) as usize,
+			(max_y - min_y + 3) as usize,
+			Rgb(15, 15, 35),
+		);
+		for point in &self.visited_tail_points {
+			frame.set(point.x as i64, -point.y as i64, Rgb(40, 110, 60));
+		}
+		for point in &self.other_points {
+			frame.set(point.x as i64, -point.y as i64, Rgb::WHITE);
+		}
+		frame.set(self.head.x as i64, -self.head.y as i64, Rgb(220, 50, 50));
+		frame
+	}
+}
+
+fn apply_input(rope: &mut Rope, input: &str, recorder: &mut Recorder) -> Result<(), ParseError> {
+	for (index, line) in input.lines().enumerate() {
+		let (direction, steps) = scan!("{} {}", line => char, u32).map_err(|e| e.at_line(index + 1))?;
+
+		let (x_movement, y_movement) = match direction {
+			'U' => (0, 1),
+			'D' => (0, -1),
+			'L' => (-1, 0),
+			'R' => (1, 0),
+			_ => return Err(ParseError::new(format!("unknown direction `{}`", direction)).at_line(index + 1)),
+		};
+
+		for _ in 0..steps {
+			rope.move_head(x_movement, y_movement);
+			recorder.record(|| rope.render());
+		}
+	}
+	recorder.record_always(|| rope.render());
+	Ok(())
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut rope = Rope::new(1);
+	apply_input(&mut rope, input, &mut Recorder::disabled()).unwrap();
+	Some(rope.get_visited_tail_point_count() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut rope = Rope::new(9);
+	apply_input(&mut rope, input, &mut Recorder::disabled()).unwrap();
+	Some(rope.get_visited_tail_point_count() as u32)
+}
+
+/// Records the rope moving through the input, with a single knot tail in part one and nine knots in part two.
+pub fn visualize(input: &str, part: u8, recorder: &mut Recorder) -> Result<(), ParseError> {
+	let mut rope = Rope::new(if part == 1 { 1 } else { 9 });
+	apply_input(&mut rope, input, recorder)
+}
+
+/// Random head motions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| format!("{} {}\n", rng.pick(&['U', 'D', 'L', 'R']), rng.range(1, 8)))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 9,
+        example: (Some(88), Some(36)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::HashSet;
+
+        fn visited_by_tail(input: &str, knots: usize) -> u32 {
+            let mut rope = vec![(0_i32, 0_i32); knots + 1];
+            let mut visited = HashSet::from([(0, 0)]);
+            for line in input.lines() {
+                let (direction, steps) = line.split_once(' ').unwrap();
+                let (dx, dy) = match direction {
+                    "U" => (0, 1),
+                    "D" => (0, -1),
+                    "L" => (-1, 0),
+                    _ => (1, 0),
+                };
+                for _ in 0..steps.parse::<u32>().unwrap() {
+                    rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                    for knot in 1..rope.len() {
+                        let (x, y) = (rope[knot - 1].0 - rope[knot].0, rope[knot - 1].1 - rope[knot].1);
+                        if x.abs() > 1 || y.abs() > 1 {
+                            rope[knot] = (rope[knot].0 + x.signum(), rope[knot].1 + y.signum());
+                        }
+                    }
+                    visited.insert(rope[knots]);
+                }
+            }
+            visited.len() as u32
+        }
+
+        generate::check(1000, 60, generate, |input| {
+            assert_eq!(part_one(input), Some(visited_by_tail(input, 1)));
+            assert_eq!(part_two(input), Some(visited_by_tail(input, 9)));
+        });
+    }
+}
@@ -0,0 +1,201 @@
+use crate::helpers::generate::Rng;
+use crate::helpers::ocr;
+use crate::helpers::viz::{Frame, Recorder, Rgb};
+use crate::Answer;
+
+#[derive(Debug)]
+pub struct SignalTimeline {
+	signals: Vec<i32>,
+}
+
+impl From<&str> for SignalTimeline {
+	fn from(input: &str) -> SignalTimeline {
+		let mut result = SignalTimeline { signals: Vec::new() };
+		result.add_not_changed_cycle();
+
+		for line in input.lines() {
+			if line.starts_with("noop") {
+				result.add_not_changed_cycle();
+			}
+			else {
+				let mut parts = line.split_whitespace();
+				parts.next();
+				let value: i32 = parts.next().unwrap().parse().unwrap();
+
+				result.add_not_changed_cycle();
+				result.add_addition_result(value);
+			}
+		}
+		
+		result
+	}
+}
+
+pub struct CrtDrawer {}
+
+impl CrtDrawer {
+	const WIDTH: usize = 40;
+	const HEIGHT: usize = 6;
+
+	/// Lit pixels of the screen, row by row. Records one frame per cycle.
+	pub fn render(signal_timeline: &SignalTimeline, recorder: &mut Recorder) -> Vec<Vec<bool>> {
+		let mut screen = vec![vec![false; CrtDrawer::WIDTH]; CrtDrawer::HEIGHT];
+		let cycles = signal_timeline.signals.len().min(CrtDrawer::WIDTH * CrtDrawer::HEIGHT);
+
+		for time in 0..cycles {
+			let sprite_center = signal_timeline.signals[time];
+			let draw_position = time % CrtDrawer::WIDTH;
+			let row = time / CrtDrawer::WIDTH;
+
+			screen[row][draw_position] = CrtDrawer::is_in_sprite(sprite_center, draw_position as i32);
+			recorder.record(|| CrtDrawer::render_frame(&screen, row, sprite_center));
+		}
+
+		screen
+	}
+
+	fn render_frame(screen: &[Vec<bool>], row: usize, sprite_center: i32) -> Frame {
+		let mut frame = Frame::new(CrtDrawer::WIDTH, CrtDrawer::HEIGHT, Rgb(15, 15, 35));
+		for offset in -1..=1 {
+			frame.set((sprite_center + offset) as i64, row as i64, Rgb(60, 60, 100));
+		}
+		for (y, pixels) in screen.iter().enumerate() {
+			for (x, lit) in pixels.iter().enumerate() {
+				if *lit {
+					frame.set(x as i64, y as i64, Rgb(255, 220, 90));
+				}
+			}
+		}
+		frame
+	}
+
+	fn is_in_sprite(sprite_center: i32, draw_position: i32) -> bool {
+		sprite_center == draw_position
+			|| sprite_center + 1 == draw_position
+			|| sprite_center - 1 == draw_position
+	}
+}
+
+impl SignalTimeline {
+	/// The register value during every cycle.
+	pub fn signals(&self) -> &[i32] {
+		&self.signals
+	}
+
+	pub fn get_signal(&self, time: u32) -> i32 {
+		self.signals[time as usize % self.signals.len()]
+	}
+
+	pub fn add_not_changed_cycle(&mut self) {
+		self.signals.push(*self.signals.last().unwrap_or(&1));
+	}
+
+	pub fn add_addition_result(&mut self, addition_operand: i32) {
+		let mut last_signal = *self.signals.last().unwrap_or(&1);
+		last_signal += addition_operand;
+		self.signals.push(last_signal);
+	}
+}
+
+fn get_part_one_signal_strength_sum(signal_timeline: &SignalTimeline) -> u32 {
+	let mut result = 0;
+	const RELEVANT_DATA_POINTS: [u32; 6] = [20, 60, 100, 140, 180, 220];
+	for data_point in RELEVANT_DATA_POINTS.iter() {
+		result += signal_timeline.get_signal(*data_point - 1) * (*data_point as i32);
+	}
+
+	result as u32
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+	let signal_timeline = SignalTimeline::from(input);
+    Some(get_part_one_signal_strength_sum(&signal_timeline))
+}
+
+/// The letters drawn on the screen, or the screen itself if it does not show known letters.
+pub fn part_two(input: &str) -> Option<Answer> {
+	let signal_timeline = SignalTimeline::from(input);
+	let screen = CrtDrawer::render(&signal_timeline, &mut Recorder::disabled());
+	match ocr::recognize(&screen) {
+		Ok(letters) => Some(Answer::Text(letters)),
+		Err(_) => Some(Answer::from_pixels(&screen)),
+	}
+}
+
+/// Records the CRT drawing the screen of part two.
+pub fn visualize(input: &str, recorder: &mut Recorder) {
+	CrtDrawer::render(&SignalTimeline::from(input), recorder);
+}
+
+/// Random `noop` and `addx` instructions covering at least 240 cycles.
+/// The register stays on the screen, so the signal strengths are never negative.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let mut output = String::new();
+	let (mut cycles, mut register) = (0, 1);
+
+	while cycles < 240.max(size) {
+		if rng.chance(0.3) {
+			output.push_str("noop\n");
+			cycles += 1;
+		} else {
+			let target = rng.range(0, 39);
+			let value = (target - register).clamp(-8, 8);
+			output.push_str(&format!("addx {}\n", value));
+			register += value;
+			cycles += 2;
+		}
+	}
+	output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    // the example draws a test pattern instead of letters.
+    crate::test_day! {
+        day: 10,
+        example: (
+            Some(13140),
+            Some(Answer::Grid(
+                [
+                    "##..##..##..##..##..##..##..##..##..##..",
+                    "###...###...###...###...###...###...###.",
+                    "####....####....####....####....####....",
+                    "#####.....#####.....#####.....#####.....",
+                    "######......######......######......####",
+                    "#######.......#######.......#######.....",
+                ]
+                .map(String::from)
+                .to_vec()
+            ))
+        ),
+    }
+
+    #[test]
+    fn test_generated() {
+        generate::check(1000, 400, generate, |input| {
+            // the register value during every cycle.
+            let mut during = Vec::new();
+            let mut register = 1_i32;
+            for line in input.lines() {
+                during.push(register);
+                if let Some(value) = line.strip_prefix("addx ") {
+                    during.push(register);
+                    register += value.parse::<i32>().unwrap();
+                }
+            }
+
+            let strength: i32 = (20..=220).step_by(40).map(|cycle| cycle as i32 * during[cycle - 1]).sum();
+            let screen: Vec<Vec<bool>> = during[..240]
+                .chunks(40)
+                .map(|row| row.iter().enumerate().map(|(x, register)| (x as i32 - register).abs() <= 1).collect())
+                .collect();
+            let letters = ocr::recognize(&screen).map_or_else(|_| Answer::from_pixels(&screen), Answer::Text);
+
+            assert_eq!(part_one(input), Some(strength as u32));
+            assert_eq!(part_two(input), Some(letters));
+        });
+    }
+}
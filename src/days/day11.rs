@@ -0,0 +1,346 @@
+use crate::helpers::generate::Rng;
+use crate::helpers::parse::{blocks, ints, ParseError};
+use crate::scan;
+
+#[derive(Debug, Clone)]
+pub struct KeepAway {
+	monkeys: Vec<Monkey>,
+	worry_level_devisor: f64,
+	acceptable_modulo: i64,
+}
+
+fn lcm(first: usize, second: usize) -> usize {
+    first * second / gcd(first, second)
+}
+
+fn gcd(first: usize, second: usize) -> usize {
+    let mut max = first;
+    let mut min = second;
+    if min > max {
+        std::mem::swap(&mut max, &mut min);
+    }
+
+    loop {
+        let res = max % min;
+        if res == 0 {
+            return min;
+        }
+
+        max = min;
+        min = res;
+    }
+}
+
+impl TryFrom<&str> for KeepAway {
+	type Error = ParseError;
+
+	fn try_from(input: &str) -> Result<KeepAway, ParseError> {
+		let mut monkeys = Vec::new();
+		for (index, monkey_part) in blocks(input).enumerate() {
+			let monkey = Monkey::try_from(monkey_part).map_err(|e| e.context(format!("monkey block {}", index + 1)))?;
+			monkeys.push(monkey);
+		}
+
+		let least_common_multiple = monkeys
+			.iter()
+			.map(|monkey| monkey.division_check.division_check_value as usize)
+			.fold(1, lcm) as i64;
+
+		Ok(KeepAway {
+			monkeys,
+			worry_level_devisor: 3.0,
+			acceptable_modulo: least_common_multiple,
+		})
+	}
+}
+
+impl KeepAway {
+	pub fn monkeys(&self) -> &[Monkey] {
+		&self.monkeys
+	}
+
+	fn play_round(&mut self) {
+		for i in 0..self.monkeys.len() {
+			let mut item_packages = Vec::new();
+			let monkey = &mut self.monkeys[i];
+			while monkey.get_item_count() > 0 {
+				let item_package = monkey.inspect_next_item(self.worry_level_devisor);
+				item_packages.push(item_package);
+			}
+
+			for item_package in item_packages {
+				self.monkeys[item_package.target as usize].add_item(item_package.item % self.acceptable_modulo);
+			}
+		}
+	}
+
+	fn set_worry_level_devisor(&mut self, worry_level_devisor: f64) {
+		self.worry_level_devisor = worry_level_devisor;
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+	items: Vec<i64>,
+	operation: Operation,
+	division_check: DivisionCheckLocator,
+	inspection_count: u32,
+}
+
+impl Monkey {
+	fn inspect_next_item(&mut self, worry_level_devisor: f64) -> ItemPackage {
+		self.inspection_count += 1;
+
+		let next_item = self.items.pop().unwrap();
+		let mut next_item = self.operation.apply(next_item);
+		next_item = (next_item as f64 / worry_level_devisor) as i64;
+
+		ItemPackage {
+			item: next_item,
+			target: self.division_check.check(next_item),
+		}
+	}
+
+	/// The worry levels of the held items, the next one to inspect last.
+	pub fn items(&self) -> &[i64] {
+		&self.items
+	}
+
+	fn get_item_count(&self) -> u32 {
+		self.items.len() as u32
+	}
+
+	fn add_item(&mut self, item: i64) {
+		self.items.push(item);
+	}
+
+	pub fn get_inspection_count(&self) -> u32 {
+		self.inspection_count
+	}
+}
+
+impl TryFrom<&str> for Monkey {
+	type Error = ParseError;
+
+	fn try_from(input: &str) -> Result<Monkey, ParseError> {
+		let lines: Vec<&str> = input.lines().map(str::trim).collect();
+		if lines.len() != 6 {
+			return Err(ParseError::new(format!("expected 6 lines, found {}", lines.len())));
+		}
+
+		// header
+		scan!("Monkey {}:", lines[0] => u32).map_err(|e| e.at_line(1))?;
+
+		// items
+		let (items,) = scan!("Starting items:{}", lines[1] => String).map_err(|e| e.at_line(2))?;
+		let items = ints(&items).map_err(|e| e.at_line(2))?;
+
+		// operation
+		let operation = Operation::try_from(lines[2]).map_err(|e| e.at_line(3))?;
+
+		// division check
+		let (divisor,) = scan!("Test: divisible by {}", lines[3] => i64).map_err(|e| e.at_line(4))?;
+		if divisor == 0 {
+			return Err(ParseError::new("cannot test divisibility by 0").at_line(4));
+		}
+
+		let (success_target,) = scan!("If true: throw to monkey {}", lines[4] => u32).map_err(|e| e.at_line(5))?;
+		let (fail_target,) = scan!("If false: throw to monkey {}", lines[5] => u32).map_err(|e| e.at_line(6))?;
+
+		Ok(Monkey {
+			items,
+			operation,
+			division_check: DivisionCheckLocator {
+				division_check_value: divisor,
+				success_target,
+				fail_target,
+			},
+			inspection_count: 0,
+		})
+	}
+}
+
+struct ItemPackage {
+	item: i64,
+	target: u32,
+}
+
+#[derive(Debug, Clone)]
+enum Operation {
+	AddSelf,
+	MulSelf,
+	Add(i64),
+	Mul(i64),
+}
+
+impl TryFrom<&str> for Operation {
+	type Error = ParseError;
+
+	fn try_from(input: &str) -> Result<Operation, ParseError> {
+		let (operator, operand) = scan!("Operation: new = old {} {}", input => char, String)?;
+		match (operator, operand.as_str()) {
+			('+', "old") => Ok(Operation::AddSelf),
+			('*', "old") => Ok(Operation::MulSelf),
+			('+', operand) => Ok(Operation::Add(scan!("{}", operand => i64)?.0)),
+			('*', operand) => Ok(Operation::Mul(scan!("{}", operand => i64)?.0)),
+			_ => Err(ParseError::new(format!("unknown operator `{}`", operator))),
+		}
+	}
+}
+
+impl Operation {
+	fn apply(&self, value: i64) -> i64 {
+		match self {
+			Operation::Add(add_value) => value + add_value,
+			Operation::Mul(mul_value) => value * mul_value,
+			Operation::AddSelf => value + value,
+			Operation::MulSelf => value * value,
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+struct DivisionCheckLocator {
+	division_check_value: i64,
+	success_target: u32,
+	fail_target: u32,
+}
+
+impl DivisionCheckLocator {
+	fn check(&self, value: i64) -> u32 {
+		if value % self.division_check_value == 0 {
+			self.success_target
+		}
+		else {
+			self.fail_target
+		}
+	}
+}
+
+fn play_rounds(keep_away: &mut KeepAway, rounds: u32) {
+	for _ in 0..rounds {
+		keep_away.play_round();
+	}
+}
+
+fn get_result(keep_away: &KeepAway) -> u64 {
+	let mut inspection_counts: Vec<_> = keep_away.monkeys.iter().map(|monkey| monkey.get_inspection_count()).collect();
+	inspection_counts.sort();
+	inspection_counts.reverse();
+	inspection_counts[0] as u64 * inspection_counts[1] as u64
+}
+
+pub fn parse(input: &str) -> KeepAway {
+	KeepAway::try_from(input).unwrap()
+}
+
+pub fn part_one(keep_away: &KeepAway) -> Option<u64> {
+    let mut keep_away = keep_away.clone();
+	play_rounds(&mut keep_away, 20);
+	
+	Some(get_result(&keep_away))
+}
+
+pub fn part_two(keep_away: &KeepAway) -> Option<u64> {
+    let mut keep_away = keep_away.clone();
+	keep_away.set_worry_level_devisor(1.0);
+
+	play_rounds(&mut keep_away, 10000);
+	
+	Some(get_result(&keep_away))
+}
+
+/// Random monkeys with distinct prime divisors. `size` is the number of items per monkey at most.
+/// Worry levels are only multiplied by small factors, so part one fits into an `i64`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let monkey_count = (size + 1).clamp(2, 8);
+	let mut divisors = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+	rng.shuffle(&mut divisors);
+
+	let monkeys: Vec<String> = (0..monkey_count)
+		.map(|index| {
+			let items: Vec<String> = (0..rng.range(1, size.max(1) as i64)).map(|_| rng.range(50, 99).to_string()).collect();
+			let operation = match rng.below(3) {
+				0 => "old + old".to_string(),
+				1 => format!("old * {}", rng.range(2, 3)),
+				_ => format!("old + {}", rng.range(1, 8)),
+			};
+			let mut target = || (index + 1 + rng.index(monkey_count - 1)) % monkey_count;
+			let (success, fail) = (target(), target());
+
+			format!(
+				"Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+				index, items.join(", "), operation, divisors[index], success, fail
+			)
+		})
+		.collect();
+	monkeys.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 11,
+        parse: parse,
+        example: (Some(10605), Some(2713310158)),
+    }
+
+    #[test]
+    fn test_generated() {
+        fn monkey_business(input: &str, rounds: usize, relief: bool) -> u64 {
+            struct Reference {
+                multiply: bool,
+                operand: Option<u64>,
+                divisor: u64,
+                targets: [usize; 2],
+            }
+
+            let number = |line: &str| ints::<u64>(line).unwrap();
+            let (mut items, monkeys): (Vec<Vec<u64>>, Vec<Reference>) = input
+                .split("\n\n")
+                .map(|monkey| {
+                    let lines: Vec<&str> = monkey.lines().collect();
+                    let reference = Reference {
+                        multiply: lines[2].contains('*'),
+                        operand: number(lines[2]).first().copied(),
+                        divisor: number(lines[3])[0],
+                        targets: [number(lines[4])[0] as usize, number(lines[5])[0] as usize],
+                    };
+                    (number(lines[1]), reference)
+                })
+                .unzip();
+            let modulo: u64 = monkeys.iter().map(|monkey| monkey.divisor).product();
+            let mut inspections = vec![0; monkeys.len()];
+
+            for _ in 0..rounds {
+                for (index, monkey) in monkeys.iter().enumerate() {
+                    for item in std::mem::take(&mut items[index]) {
+                        inspections[index] += 1;
+                        let operand = monkey.operand.unwrap_or(item);
+                        let item = if monkey.multiply { item * operand } else { item + operand };
+                        let item = if relief { item / 3 } else { item % modulo };
+
+                        let target = monkey.targets[(item % monkey.divisor != 0) as usize];
+                        items[target].push(item);
+                    }
+                }
+            }
+
+            inspections.sort_unstable_by(|a, b| b.cmp(a));
+            inspections[0] * inspections[1]
+        }
+
+        generate::check(50, 8, generate, |input| {
+            let keep_away = parse(input);
+            assert_eq!(part_one(&keep_away), Some(monkey_business(input, 20, true)));
+            assert_eq!(part_two(&keep_away), Some(monkey_business(input, 10000, false)));
+        });
+
+        // every monkey still starts with an item.
+        let input = generate(&mut Rng::new(0), 0);
+        assert_eq!(part_one(&parse(&input)), Some(monkey_business(&input, 20, true)));
+    }
+}
@@ -0,0 +1,324 @@
+use std::{
+	cmp::Ordering,
+	collections::{BinaryHeap, HashMap, HashSet},
+	hash::Hash,
+};
+
+use crate::helpers::generate::Rng;
+use crate::helpers::viz::{Frame, Recorder, Rgb};
+
+#[derive(Debug)]
+pub struct Mountains {
+	heights: Vec<Vec<u32>>,
+	start: Point,
+	end: Point,
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct Point {
+	pub x: usize,
+	pub y: usize,
+}
+
+impl Point {
+	fn new(x: usize, y: usize) -> Self {
+		Point { x, y }
+	}
+
+	fn zero() -> Self {
+		Point { x: 0, y: 0 }
+	}
+}
+
+impl From<&str> for Mountains {
+	fn from(s: &str) -> Self {
+		let mut heights = Vec::new();
+		let mut start = Point::zero();
+		let mut end = Point::zero();
+
+		for (x, line) in s.lines().enumerate() {
+			let mut row = Vec::new();
+			for (y, c) in line.chars().enumerate() {
+				match c {
+					'S' => {
+						row.push(0);
+						start = Point::new(x, y);
+					},
+					'E' => {
+						row.push(26);
+						end = Point::new(x, y);
+					},
+					_ => row.push(c as u32 - 97),
+				}
+			}
+			heights.push(row);
+		}
+
+		Mountains { heights, start, end }
+	}
+}
+
+struct Visit<T> {
+	point: T,
+	distance: u32,
+}
+
+impl<T> Ord for Visit<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+    }
+}
+
+impl<T> PartialOrd for Visit<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> PartialEq for Visit<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.distance.eq(&other.distance)
+    }
+}
+
+impl<T> Eq for Visit<T> {}
+
+impl Mountains {
+	/// The heights from 0 (`a`) to 25 (`z`), row by row.
+	pub fn heights(&self) -> &[Vec<u32>] {
+		&self.heights
+	}
+
+	pub fn start(&self) -> Point {
+		self.start
+	}
+
+	pub fn end(&self) -> Point {
+		self.end
+	}
+
+	fn dijkstra(&self, recorder: &mut Recorder) -> u32 {
+		self.dijkstra_from(self.start, recorder)
+	}
+
+	fn dijkstra_from(&self, from: Point, recorder: &mut Recorder) -> u32 {
+		let mut distances = HashMap::new();
+		let mut visited = HashSet::new();
+		let mut queue = BinaryHeap::new();
+
+		distances.insert(from, 0);
+		queue.push(Visit { point: from, distance: 0 });
+
+		while let Some(Visit { point, distance }) = queue.pop() {
+			if visited.contains(&point) {
+				continue;
+			}
+
+			if point == self.end {
+				return distance;
+			}
+
+			visited.insert(point);
+			recorder.record(|| self.render(&visited, &queue));
+
+			for neighbour in self.get_neighbours(point) {
+				let new_distance = distance + 1;
+				if let Some(old_distance) = distances.get(&neighbour) {
+					if new_distance < *old_distance {
+						distances.insert(neighbour, new_distance);
+						queue.push(Visit { point: neighbour, distance: new_distance });
+					}
+				} else {
+					distances.insert(neighbour, new_distance);
+					queue.push(Visit { point: neighbour, distance: new_distance });
+				}
+			}
+		}
+		u32::MAX
+	}
+
+	fn get_shortest_path_from_lowest(&self, recorder: &mut Recorder) -> u32 {
+		let mut shortest = u32::MAX;
+		for x in 0..self.heights.len() {
+			for y in 0..self.heights[0].len() {
+				let point = Point::new(x, y);
+				if self.get_height(point) != 0 {
+					continue;
+				}
+				
+				let distance = self.dijkstra_from(point, recorder);
+				if distance < shortest {
+					shortest = distance;
+				}
+			}
+		}
+		shortest
+	}
+
+	fn get_neighbours(&self, point: Point) -> Vec<Point> {
+		let mut neighbours = Vec::new();
+
+		if point.x > 0 {
+			let left = Point::new(point.x - 1, point.y);
+			if self.is_walkable(point, left) {
+				neighbours.push(left);
+			}
+		}
+		if point.x < self.heights.len() - 1 {
+			let right = Point::new(point.x + 1, point.y);
+			if self.is_walkable(point, right) {
+				neighbours.push(right);
+			}
+		}
+		if point.y > 0 {
+			let up = Point::new(point.x, point.y - 1);
+			if self.is_walkable(point, up) {
+				neighbours.push(up);
+			}
+		}
+		if point.y < self.heights[0].len() - 1 {
+			let down = Point::new(point.x, point.y + 1);
+			if self.is_walkable(point, down) {
+				neighbours.push(down);
+			}
+		}
+
+		neighbours
+	}
+
+	fn is_walkable(&self, from: Point, to: Point) -> bool {
+		let from_height = self.get_height(from);
+		let to_height = self.get_height(to);
+		to_height as i32 - from_height as i32 <= 1
+	}
+
+	fn get_height(&self, point: Point) -> u32 {
+		self.heights[point.x][point.y]
+	}
+
+	/// Heights as shades of green, visited cells in blue and the search frontier in yellow.
+	fn render(&self, visited: &HashSet<Point>, queue: &BinaryHeap<Visit<Point>>) -> Frame {
+		let mut frame = Frame::new(self.heights[0].len(), self.heights.len(), Rgb::BLACK);
+		let set = |frame: &mut Frame, point: Point, color: Rgb| frame.set(point.y as i64, point.x as i64, color);
+
+		for (x, row) in self.heights.iter().enumerate() {
+			for (y, height) in row.iter().enumerate() {
+				let shade = (40 + height * 8) as u8;
+				set(&mut frame, Point::new(x, y), Rgb(shade / 3, shade, shade / 3));
+			}
+		}
+		for point in visited {
+			let shade = (60 + self.get_height(*point) * 7) as u8;
+			set(&mut frame, *point, Rgb(shade / 3, shade / 2, shade));
+		}
+		for visit in queue.iter() {
+			set(&mut frame, visit.point, Rgb(250, 220, 60));
+		}
+		set(&mut frame, self.start, Rgb(230, 40, 40));
+		set(&mut frame, self.end, Rgb(230, 40, 40));
+		frame
+	}
+}
+
+pub fn parse(input: &str) -> Mountains {
+	Mountains::from(input)
+}
+
+pub fn part_one(mountains: &Mountains) -> Option<u32> {
+	Some(mountains.dijkstra(&mut Recorder::disabled()))
+}
+
+pub fn part_two(mountains: &Mountains) -> Option<u32> {
+	Some(mountains.get_shortest_path_from_lowest(&mut Recorder::disabled()))
+}
+
+/// Records the path search of the given part.
+pub fn visualize(mountains: &Mountains, part: u8, recorder: &mut Recorder) {
+	if part == 1 {
+		mountains.dijkstra(recorder);
+	} else {
+		mountains.get_shortest_path_from_lowest(recorder);
+	}
+}
+
+/// A random height map rising towards the bottom right corner, where the signal is.
+/// Some cells are lowered, so not every start can reach the top.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let (width, height) = (size + 20, size / 2 + 8);
+	let steps = width + height - 2;
+
+	let mut rows: Vec<Vec<char>> = (0..height)
+		.map(|y| {
+			(0..width)
+				.map(|x| {
+					let mut elevation = ((x + y) * 25 / steps) as u8;
+					if rng.chance(0.2) {
+						elevation = elevation.saturating_sub(rng.range(1, 10) as u8);
+					}
+					(b'a' + elevation) as char
+				})
+				.collect()
+		})
+		.collect();
+
+	rows[rng.index(height)][rng.index(width / 2)] = 'S';
+	rows[height - 1][width - 1] = 'E';
+	rows.iter().map(|row| row.iter().collect::<String>() + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 12,
+        parse: parse,
+        example: (Some(31), Some(29)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::VecDeque;
+
+        // breadth-first search downhill from the signal, i.e. all shortest paths towards it.
+        fn distances_to_end(input: &str) -> Vec<Vec<(char, u32)>> {
+            let elevation = |c: char| match c {
+                'S' => 0,
+                'E' => 26,
+                c => c as i32 - 'a' as i32,
+            };
+            let grid: Vec<Vec<char>> = input.lines().map(|line| line.chars().collect()).collect();
+            let mut distances: Vec<Vec<(char, u32)>> =
+                grid.iter().map(|row| row.iter().map(|c| (*c, u32::MAX)).collect()).collect();
+
+            let end = (grid.len() - 1, grid[0].len() - 1);
+            distances[end.0][end.1].1 = 0;
+            let mut queue = VecDeque::from([end]);
+            while let Some((y, x)) = queue.pop_front() {
+                let distance = distances[y][x].1;
+                let neighbours = [(y.wrapping_sub(1), x), (y + 1, x), (y, x.wrapping_sub(1)), (y, x + 1)];
+                for (ny, nx) in neighbours {
+                    let Some(&(c, old)) = distances.get(ny).and_then(|row| row.get(nx)) else {
+                        continue;
+                    };
+                    if old == u32::MAX && elevation(grid[y][x]) - elevation(c) <= 1 {
+                        distances[ny][nx].1 = distance + 1;
+                        queue.push_back((ny, nx));
+                    }
+                }
+            }
+            distances
+        }
+
+        generate::check(200, 20, generate, |input| {
+            let cells: Vec<(char, u32)> = distances_to_end(input).into_iter().flatten().collect();
+            let from_start = cells.iter().find(|(c, _)| *c == 'S').unwrap().1;
+            let from_lowest = cells.iter().filter(|(c, _)| *c == 'S' || *c == 'a').map(|(_, d)| *d).min().unwrap();
+
+            let mountains = parse(input);
+            assert_eq!(part_one(&mountains), Some(from_start));
+            assert_eq!(part_two(&mountains), Some(from_lowest));
+        });
+    }
+}
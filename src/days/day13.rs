@@ -0,0 +1,158 @@
+use std::cmp::Ordering;
+
+use serde::Deserialize;
+
+use crate::helpers::generate::Rng;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Packet {
+	Terminal(u8),
+	Nesting(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+	fn eq(&self, other: &Self) -> bool {
+		self.cmp(other) == Ordering::Equal
+	}
+}
+impl Eq for Packet {}
+
+impl PartialOrd for Packet {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Packet {
+	fn cmp(&self, other: &Self) -> Ordering {
+		use Packet::*;
+		match (self, other) {
+			(Terminal(a), Terminal(b)) => a.cmp(b),
+			(Nesting(a), Nesting(b)) => a.cmp(b),
+			(Terminal(a), Nesting(b)) => [Terminal(*a)][..].cmp(b),
+			(Nesting(a), Terminal(b)) => a.as_slice().cmp(&[Terminal(*b)]),
+		}
+	}
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut lines = input.lines();
+	
+	let mut wrong_order_sum = 0;
+	let mut index = 1;
+	while let (Some(line1), Some(line2)) = (lines.next(), lines.next()) {
+		let packet1 = serde_json::from_str::<Packet>(line1).unwrap();
+		let packet2 = serde_json::from_str::<Packet>(line2).unwrap();
+		lines.next();
+
+		if packet1 < packet2 {
+			wrong_order_sum += index;
+		}
+		index += 1;
+	}
+
+	Some(wrong_order_sum)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+	let mut packets = input.lines()
+		.filter(|x| !x.is_empty())
+		.map(|x| serde_json::from_str::<Packet>(x).unwrap())
+		.collect::<Vec<_>>();
+
+	let divider1: Packet = serde_json::from_str("[[2]]").unwrap();
+	let divider2: Packet = serde_json::from_str("[[6]]").unwrap();
+	packets.push(divider1.clone());
+	packets.push(divider2.clone());
+
+	packets.sort_unstable();
+
+	let divider_index_1 = packets.binary_search(&divider1).unwrap() as u32 + 1;
+	let divider_index_2 = packets.binary_search(&divider2).unwrap() as u32 + 1;
+    
+	Some(divider_index_1 * divider_index_2)
+}
+
+/// Random pairs of nested packets.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let pairs: Vec<String> = (0..size)
+		.map(|_| format!("{}\n{}\n", generate_divider_free_packet(rng), generate_divider_free_packet(rng)))
+		.collect();
+	pairs.join("\n")
+}
+
+/// Packets that equal a divider (e.g. `[2]` or `[[[6]]]`) have no defined position in the sorted list.
+fn generate_divider_free_packet(rng: &mut Rng) -> String {
+	loop {
+		let packet = generate_packet(rng, 0);
+		if !matches!(packet.replace(['[', ']'], "").as_str(), "2" | "6") {
+			return packet;
+		}
+	}
+}
+
+fn generate_packet(rng: &mut Rng, depth: usize) -> String {
+	let items: Vec<String> = (0..rng.range(0, 4))
+		.map(|_| {
+			if depth < 3 && rng.chance(0.3) {
+				generate_packet(rng, depth + 1)
+			} else {
+				rng.range(0, 10).to_string()
+			}
+		})
+		.collect();
+	format!("[{}]", items.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 13,
+        example: (Some(13), Some(140)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use serde_json::Value;
+
+        fn compare(left: &Value, right: &Value) -> Ordering {
+            match (left, right) {
+                (Value::Number(a), Value::Number(b)) => a.as_u64().cmp(&b.as_u64()),
+                (Value::Array(a), Value::Array(b)) => a
+                    .iter()
+                    .zip(b)
+                    .map(|(a, b)| compare(a, b))
+                    .find(|order| order.is_ne())
+                    .unwrap_or_else(|| a.len().cmp(&b.len())),
+                (Value::Array(_), _) => compare(left, &Value::Array(vec![right.clone()])),
+                (_, _) => compare(&Value::Array(vec![left.clone()]), right),
+            }
+        }
+
+        generate::check(1000, 30, generate, |input| {
+            let packets: Vec<Value> = input
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(|line| serde_json::from_str(line).unwrap())
+                .collect();
+
+            let ordered: usize = packets
+                .chunks(2)
+                .enumerate()
+                .filter(|(_, pair)| compare(&pair[0], &pair[1]).is_lt())
+                .map(|(index, _)| index + 1)
+                .sum();
+
+            let (first, second) = (serde_json::json!([[2]]), serde_json::json!([[6]]));
+            let before = |divider: &Value| packets.iter().filter(|packet| compare(packet, divider).is_lt()).count();
+            let decoder_key = (before(&first) + 1) * (before(&second) + 2);
+
+            assert_eq!(part_one(input), Some(ordered as u32));
+            assert_eq!(part_two(input), Some(decoder_key as u32));
+        });
+    }
+}
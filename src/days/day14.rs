@@ -0,0 +1,321 @@
+use crate::helpers::generate::Rng;
+use crate::helpers::viz::{Frame, Recorder, Rgb};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Point {
+	pub x: usize,
+	pub y: usize,
+}
+
+impl From<&str> for Point {
+	fn from(input: &str) -> Self {
+		let mut parts = input.split(",");
+		let x = parts.next().unwrap().parse::<usize>().unwrap();
+		let y = parts.next().unwrap().parse::<usize>().unwrap();
+		Self { x, y }
+	}
+}
+
+#[derive(Debug)]
+struct StoneTrail {
+	points: Vec<Point>,
+	max_y: usize,
+}
+
+impl From<&str> for StoneTrail {
+	fn from(input: &str) -> Self {
+		let points: Vec<Point> = input.split(" -> ")
+			.map(Point::from)
+			.collect();
+
+		let max_y = points.iter()
+			.max_by_key(|x| x.y)
+			.unwrap()
+			.y;
+
+		Self { points, max_y }
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Field {
+	Empty,
+	Stone,
+	Sand,
+}
+
+#[derive(Debug)]
+pub struct Grid {
+	fields: Vec<Vec<Field>>,
+}
+
+impl From<&str> for Grid {
+	fn from(input: &str) -> Self {
+		let mut stone_trails = Vec::new();
+		let mut max_y = 0;
+
+		for line in input.lines() {
+			let stone_trail = StoneTrail::from(line);
+
+			if stone_trail.max_y > max_y {
+				max_y = stone_trail.max_y;
+			}
+
+			stone_trails.push(StoneTrail::from(line));
+		}
+
+		let mut fields = Vec::new();
+		for _ in 0..=max_y {
+			let mut row = Vec::new();
+			for _ in 0..1000 {
+				row.push(Field::Empty);
+			}
+			fields.push(row);
+		}
+
+		for stone_trail in stone_trails {
+			let mut start_point = stone_trail.points[0];
+			for point in stone_trail.points.iter().skip(1) {
+				fields[point.y][point.x] = Field::Stone;
+				if start_point.x == point.x {
+					let mut y = start_point.y;
+					while y != point.y {
+						fields[y][start_point.x] = Field::Stone;
+						if y < point.y {
+							y += 1;
+						}
+						else {
+							y -= 1;
+						}
+					}
+				}
+				else if start_point.y == point.y {
+					let mut x = start_point.x;
+					while x != point.x {
+						fields[start_point.y][x] = Field::Stone;
+						if x < point.x {
+							x += 1;
+						}
+						else {
+							x -= 1;
+						}
+					}
+				}
+				start_point = *point;
+			}
+		}
+
+		Grid { fields }
+	}
+}
+
+impl Grid {
+	/// The fields row by row, indexed by `y` and then `x`.
+	pub fn fields(&self) -> &[Vec<Field>] {
+		&self.fields
+	}
+
+	fn is_spawn_free(&self) -> bool {
+		self.fields[0][500] == Field::Empty
+	}
+
+	fn spawn_sand(&mut self) -> bool {
+		let mut sand_position = Point { x: 500, y: 0 };
+
+		while let Some(next_position) = self.get_next_sand_position(&sand_position) {
+			if next_position == sand_position {
+				self.fields[sand_position.y][sand_position.x] = Field::Sand;
+				return true;
+			}
+			sand_position = next_position;
+		}
+		false
+		
+	}
+
+	fn get_next_sand_position(&self, sand_position: &Point) -> Option<Point> {
+		let mut next_position = Point { x: sand_position.x, y: sand_position.y + 1 };
+		if next_position.y > self.fields.len() - 1 {
+			return None;
+		}
+		if self.fields[next_position.y][next_position.x] == Field::Empty {
+			return Some(next_position);
+		}
+		next_position = Point { x: sand_position.x - 1, y: sand_position.y + 1 };
+		if self.fields[next_position.y][next_position.x] == Field::Empty {
+			return Some(next_position);
+		}
+		next_position = Point { x: sand_position.x + 1, y: sand_position.y + 1 };
+		if self.fields[next_position.y][next_position.x] == Field::Empty {
+			return Some(next_position);
+		}
+		Some(*sand_position)
+	}
+
+	fn spawn_bedrock(&mut self) {
+		self._spawn_line_with(Field::Empty);
+		self._spawn_line_with(Field::Stone);
+	}
+
+	fn render(&self) -> Frame {
+		// the bedrock spans the whole grid, so it does not count towards the visible area.
+		let used_columns = self.fields.iter()
+			.filter(|row| row.iter().any(|field| *field != Field::Stone))
+			.flat_map(|row| row.iter().enumerate())
+			.filter(|(_, field)| **field != Field::Empty)
+			.map(|(x, _)| x);
+		let (min_x, max_x) = used_columns.fold((500, 500), |(min, max), x| (x.min(min), x.max(max)));
+
+		let mut frame = Frame::with_origin((min_x as i64 - 1, 0), max_x - min_x + 3, self.fields.len(), Rgb(15, 15, 35));
+		for (y, row) in self.fields.iter().enumerate() {
+			for (x, field) in row.iter().enumerate().take(max_x + 2).skip(min_x.saturating_sub(1)) {
+				match field {
+					Field::Stone => frame.set(x as i64, y as i64, Rgb(120, 120, 130)),
+					Field::Sand => frame.set(x as i64, y as i64, Rgb(230, 190, 90)),
+					Field::Empty => {}
+				}
+			}
+		}
+		frame.set(500, 0, Rgb(230, 40, 40));
+		frame
+	}
+
+	fn _spawn_line_with(&mut self, field: Field) {
+		let mut line = Vec::new();
+		for _ in 0..self.fields[0].len() {
+			line.push(field);
+		}
+		self.fields.push(line);
+	}
+}
+
+fn count_possible_sand_spawns(grid: &mut Grid, recorder: &mut Recorder) -> u32 {
+	let mut count = 0;
+	// rocks may form a basin that fills up to the source before sand falls into the void.
+	while grid.is_spawn_free() && grid.spawn_sand() {
+		count += 1;
+		recorder.record(|| grid.render());
+	}
+	recorder.record_always(|| grid.render());
+	count
+}
+
+fn count_until_start_blocked(grid: &mut Grid, recorder: &mut Recorder) -> u32 {
+	let mut count = 0;
+	while grid.is_spawn_free() && grid.spawn_sand() {
+		count += 1;
+		recorder.record(|| grid.render());
+	}
+	recorder.record_always(|| grid.render());
+	count
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let mut grid = Grid::from(input);
+	let spawn_count = count_possible_sand_spawns(&mut grid, &mut Recorder::disabled());
+
+	Some(spawn_count)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let mut grid = Grid::from(input);
+	grid.spawn_bedrock();
+	let spawn_count = count_until_start_blocked(&mut grid, &mut Recorder::disabled());
+
+	Some(spawn_count)
+}
+
+/// Records the sand falling in the given part.
+pub fn visualize(input: &str, part: u8, recorder: &mut Recorder) {
+	let mut grid = Grid::from(input);
+	if part == 1 {
+		count_possible_sand_spawns(&mut grid, recorder);
+	} else {
+		grid.spawn_bedrock();
+		count_until_start_blocked(&mut grid, recorder);
+	}
+}
+
+/// Random rock paths below the sand source.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	let spread = 10 + size as i64;
+	(0..size)
+		.map(|_| {
+			let mut point = (rng.range(500 - spread, 500 + spread), rng.range(2, 6 + size as i64));
+			let mut path = vec![format!("{},{}", point.0, point.1)];
+			for segment in 0..rng.range(1, 3) {
+				let length = rng.range(1, 5) * if rng.chance(0.5) { 1 } else { -1 };
+				if segment % 2 == 0 {
+					point.0 += length;
+				} else {
+					point.1 = (point.1 + length).max(2);
+				}
+				path.push(format!("{},{}", point.0, point.1));
+			}
+			path.join(" -> ") + "\n"
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 14,
+        example: (Some(24), Some(93)),
+    }
+
+    #[test]
+    fn test_generated() {
+        use std::collections::HashSet;
+
+        fn resting_sand(input: &str, floor: bool) -> u32 {
+            let mut blocked = HashSet::new();
+            for line in input.lines() {
+                let points: Vec<(i32, i32)> = line
+                    .split(" -> ")
+                    .map(|point| {
+                        let (x, y) = point.split_once(',').unwrap();
+                        (x.parse().unwrap(), y.parse().unwrap())
+                    })
+                    .collect();
+                for pair in points.windows(2) {
+                    let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+                    for x in x1.min(x2)..=x1.max(x2) {
+                        for y in y1.min(y2)..=y1.max(y2) {
+                            blocked.insert((x, y));
+                        }
+                    }
+                }
+            }
+
+            let lowest = blocked.iter().map(|(_, y)| *y).max().unwrap();
+            let mut count = 0;
+            while !blocked.contains(&(500, 0)) {
+                let (mut x, mut y) = (500, 0);
+                loop {
+                    if y == lowest + 1 && floor {
+                        break;
+                    }
+                    if y > lowest && !floor {
+                        return count;
+                    }
+                    match [x, x - 1, x + 1].into_iter().find(|x| !blocked.contains(&(*x, y + 1))) {
+                        Some(next) => (x, y) = (next, y + 1),
+                        None => break,
+                    }
+                }
+                blocked.insert((x, y));
+                count += 1;
+            }
+            count
+        }
+
+        generate::check(300, 20, generate, |input| {
+            assert_eq!(part_one(input), Some(resting_sand(input, false)));
+            assert_eq!(part_two(input), Some(resting_sand(input, true)));
+        });
+    }
+}
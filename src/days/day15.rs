@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use crate::helpers::generate::Rng;
+use crate::helpers::parse::{ints, parse_lines, ParseError};
+use crate::params::{Param, Params};
+
+pub const PARAMS: &[Param] = &[
+	Param::new("row", 10, 2_000_000),
+	Param::new("max_coordinate", 20, 4_000_000),
+];
+
+#[derive(Debug)]
+pub struct Point {
+	pub x: i32,
+	pub y: i32,
+}
+
+#[derive(Debug)]
+pub struct Range {
+	pub start: i32,
+	pub end: i32,
+}
+
+impl Range {
+	fn new(start: i32, end: i32) -> Self {
+		Range { start, end }
+	}
+
+	fn len(&self) -> i32 {
+		self.end - self.start + 1
+	}
+}
+
+#[derive(Debug)]
+pub struct Sensor {
+	position: Point,
+	beacon_position: Point,
+	distance: u32,
+}
+
+impl Sensor {
+	pub fn position(&self) -> &Point {
+		&self.position
+	}
+
+	pub fn beacon_position(&self) -> &Point {
+		&self.beacon_position
+	}
+
+	/// The manhattan distance to the beacon, every position up to it has no other beacon.
+	pub fn distance(&self) -> u32 {
+		self.distance
+	}
+}
+
+impl TryFrom<&str> for Sensor {
+	type Error = ParseError;
+
+	fn try_from(input: &str) -> Result<Self, ParseError> {
+		let [x, y, beacon_x, beacon_y]: [i32; 4] = ints(input)?
+			.try_into()
+			.map_err(|_| ParseError::new(format!("expected four coordinates in `{}`", input)))?;
+
+		let distance = (beacon_x - x).abs() + (beacon_y - y).abs();
+
+		Ok(Sensor {
+			position: Point { x, y },
+			beacon_position: Point {
+				x: beacon_x,
+				y: beacon_y,
+			},
+			distance: distance as u32,
+		})
+	}
+}
+
+#[derive(Debug)]
+pub struct Sensors {
+	sensors: Vec<Sensor>,
+}
+
+impl TryFrom<&str> for Sensors {
+	type Error = ParseError;
+
+	fn try_from(input: &str) -> Result<Self, ParseError> {
+		let sensors = parse_lines(input, |line| Sensor::try_from(line))?;
+
+		Ok(Sensors { sensors })
+	}
+}
+
+impl Sensors {
+	pub fn sensors(&self) -> &[Sensor] {
+		&self.sensors
+	}
+
+	fn find_no_beacon_positions_in_line(&self, line_nr: i32) -> u32 {
+		let ranges = self.get_blocked_positions_in_line(line_nr);
+
+		let blocked_length: i32 = ranges.iter()
+			.map(|range| range.len())
+			.sum();
+		let beacons_in_line= self.sensors.iter()
+			.filter(|sensor| sensor.beacon_position.y == line_nr)
+			.map(|sensor| sensor.beacon_position.x)
+			.collect::<HashSet<i32>>();
+
+		blocked_length as u32 - beacons_in_line.len() as u32
+	}
+
+	fn find_hole(&self, max_coordinate: i32) -> u64 {
+		for y in 0..=max_coordinate {
+			let blocked_ranges = self.get_blocked_positions_in_line(y);
+
+			if blocked_ranges.len() <= 1 {
+				continue;
+			}
+
+			let x = blocked_ranges[0].end + 1;
+			return (x as u64 * 4000000) + y as u64;
+		}
+		0
+	}
+
+	fn get_blocked_positions_in_line(&self, line_nr: i32) -> Vec<Range> {
+		let mut ranges = Vec::new();
+		for sensor in &self.sensors {
+			let shortest_dist = (sensor.position.y - line_nr).abs();
+			if shortest_dist > sensor.distance as i32 {
+				continue;
+			}
+
+			let max_x_dist = (sensor.distance as i32 - shortest_dist).abs();
+			let min_x = sensor.position.x - max_x_dist;
+			let max_x = sensor.position.x + max_x_dist;
+			ranges.push(Range::new(min_x, max_x));
+		}
+
+		combine_ranges(ranges)
+	}
+}
+
+fn combine_ranges(ranges: Vec<Range>) -> Vec<Range> {
+	if ranges.is_empty() {
+		return ranges;
+	}
+	let mut combined_ranges = Vec::new();
+	let mut ranges = ranges;
+	ranges.sort_by_key(|range| range.start);
+
+	let mut current_range = ranges.remove(0);
+	for range in ranges {
+		if range.start - 1 <= current_range.end {
+			if range.end > current_range.end {
+				current_range.end = range.end;
+			}
+		} else {
+			combined_ranges.push(current_range);
+			current_range = range;
+		}
+	}
+	combined_ranges.push(current_range);
+
+	combined_ranges
+}
+
+pub fn parse(input: &str) -> Sensors {
+	Sensors::try_from(input).unwrap()
+}
+
+pub fn part_one(sensors: &Sensors, params: &Params) -> Option<u32> {
+    Some(sensors.find_no_beacon_positions_in_line(params.get("row")))
+}
+
+pub fn part_two(sensors: &Sensors, params: &Params) -> Option<u64> {
+	Some(sensors.find_hole(params.get("max_coordinate")))
+}
+
+/// Random sensors and their beacons around the example's search area.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+	(0..size)
+		.map(|_| {
+			let (x, y) = (rng.range(0, 20), rng.range(0, 20));
+			let (beacon_x, beacon_y) = (x + rng.range(-6, 6), y + rng.range(-6, 6));
+			format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", x, y, beacon_x, beacon_y)
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::generate;
+
+    crate::test_day! {
+        day: 15,
+        parse: parse,
+        params: PARAMS,
+        example: (Some(26), Some(56000011)),
+    }
+
+    #[test]
+    fn test_generated() {
+        generate::check(1000, 20, generate, |input| {
+            let sensors: Vec<[i64; 4]> = input.lines().map(|line| ints(line).unwrap().try_into().unwrap()).collect();
+            let covered = |x: i64, y: i64| {
+                sensors.iter().any(|[sx, sy, bx, by]| (sx - x).abs() + (sy - y).abs() <= (sx - bx).abs() + (sy - by).abs())
+            };
+            let is_beacon = |x: i64, y: i64| sensors.iter().any(|[_, _, bx, by]| *bx == x && *by == y);
+
+            let params = Params::example(PARAMS);
+            let row: i64 = params.get("row");
+            let no_beacon = (-50..=70).filter(|x| covered(*x, row) && !is_beacon(*x, row)).count();
+
+            let sensors_parsed = parse(input);
+            assert_eq!(part_one(&sensors_parsed, &params), Some(no_beacon as u32));
+
+            // any hole that is found has to be uncovered and lie in the searched rows.
+            let frequency = part_two(&sensors_parsed, &params).unwrap();
+            if frequency != 0 {
+                let (x, y) = ((frequency / 4_000_000) as i64, (frequency % 4_000_000) as i64);
+                assert!((0..=params.get::<i64>("max_coordinate")).contains(&y));
+                assert!(!covered(x, y), "({}, {}) is covered", x, y);
+            }
+        });
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * Encrypted puzzle inputs, so they can be committed and shared without publishing them.
+ * `src/inputs/{day}.txt` is stored as `src/inputs/{day}.txt.enc`, encrypted with XChaCha20-Poly1305.
+ * The key is read from the `AOC_INPUT_KEY` environment variable (64 hex digits),
+ * or from the file named by `AOC_INPUT_KEY_FILE` (default: `.aoc-input.key`).
+ * Manage files with `cargo inputs <keygen|encrypt|decrypt> [day]`.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+
+pub const KEY_ENV: &str = "AOC_INPUT_KEY";
+pub const KEY_FILE_ENV: &str = "AOC_INPUT_KEY_FILE";
+pub const DEFAULT_KEY_FILE: &str = ".aoc-input.key";
+
+/// Identifies encrypted input files and their format version.
+const MAGIC: &[u8] = b"AOCENC1\n";
+const NONCE_LEN: usize = 24;
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    MissingKey,
+    InvalidKey(String),
+    /// The file is not an encrypted input.
+    InvalidFormat,
+    /// The key is wrong, or the file was modified or belongs to another day.
+    Authentication,
+    Io(io::Error),
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncryptionError::MissingKey => write!(
+                f,
+                "no key found, set `{}` or create `{}` with `cargo inputs keygen`",
+                KEY_ENV, DEFAULT_KEY_FILE
+            ),
+            EncryptionError::InvalidKey(reason) => write!(f, "invalid key: {}", reason),
+            EncryptionError::InvalidFormat => write!(f, "not an encrypted input file"),
+            EncryptionError::Authentication => write!(
+                f,
+                "decryption failed, the key is wrong or the file was modified"
+            ),
+            EncryptionError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for EncryptionError {}
+
+impl From<io::Error> for EncryptionError {
+    fn from(e: io::Error) -> Self {
+        EncryptionError::Io(e)
+    }
+}
+
+/// A 256 bit key.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl fmt::Debug for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Key(..)")
+    }
+}
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, EncryptionError> {
+        let hex = hex.trim();
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(EncryptionError::InvalidKey(
+                "expected 64 hex digits".to_string(),
+            ));
+        }
+        let mut key = [0; 32];
+        for (byte, digits) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let digits = std::str::from_utf8(digits).unwrap();
+            *byte = u8::from_str_radix(digits, 16).map_err(|_| {
+                EncryptionError::InvalidKey(format!("`{}` is not a hex number", digits))
+            })?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// The key from a non-empty `AOC_INPUT_KEY`, or else from the key file. `Ok(None)` if neither exists.
+    pub fn from_env() -> Result<Option<Self>, EncryptionError> {
+        // CI sets the variable to an empty string when the secret is not available.
+        if let Some(hex) = env::var(KEY_ENV).ok().filter(|hex| !hex.is_empty()) {
+            return Key::from_hex(&hex).map(Some);
+        }
+        match fs::read_to_string(key_file_path()) {
+            Ok(hex) => Key::from_hex(&hex).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+pub fn key_file_path() -> PathBuf {
+    env::var_os(KEY_FILE_ENV).map_or_else(|| PathBuf::from(DEFAULT_KEY_FILE), PathBuf::from)
+}
+
+/// The encrypted counterpart of a plaintext input path.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".enc");
+    PathBuf::from(name)
+}
+
+/// Encrypts an input. The day is authenticated too, so files cannot be swapped between days.
+pub fn encrypt(key: &Key, day: u8, plaintext: &str) -> Vec<u8> {
+    let cipher = XChaCha20Poly1305::new(&key.0.into());
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(
+            &nonce,
+            Payload {
+                msg: plaintext.as_bytes(),
+                aad: &[day],
+            },
+        )
+        .expect("inputs are far below the size limit of the cipher");
+
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, day: u8, data: &[u8]) -> Result<String, EncryptionError> {
+    let data = data
+        .strip_prefix(MAGIC)
+        .filter(|data| data.len() >= NONCE_LEN)
+        .ok_or(EncryptionError::InvalidFormat)?;
+    let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+
+    let cipher = XChaCha20Poly1305::new(&key.0.into());
+    let plaintext = cipher
+        .decrypt(
+            XNonce::from_slice(nonce),
+            Payload {
+                msg: ciphertext,
+                aad: &[day],
+            },
+        )
+        .map_err(|_| EncryptionError::Authentication)?;
+    String::from_utf8(plaintext).map_err(|_| EncryptionError::InvalidFormat)
+}
+
+/// Reads and decrypts `{path}.enc`. `Ok(None)` if there is no encrypted file.
+pub fn read_encrypted(path: &Path, day: u8) -> Result<Option<String>, EncryptionError> {
+    let data = match fs::read(encrypted_path(path)) {
+        Ok(data) => data,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let key = Key::from_env()?.ok_or(EncryptionError::MissingKey)?;
+    decrypt(&key, day, &data).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, 7, "$ cd /\n$ ls\n");
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(decrypt(&key, 7, &encrypted).unwrap(), "$ cd /\n$ ls\n");
+
+        // every encryption uses a fresh nonce.
+        assert_ne!(encrypt(&key, 7, "$ cd /\n$ ls\n"), encrypted);
+    }
+
+    #[test]
+    fn test_rejects_wrong_key_day_and_modifications() {
+        let key = Key::generate();
+        let encrypted = encrypt(&key, 7, "input");
+
+        assert!(matches!(
+            decrypt(&Key::generate(), 7, &encrypted),
+            Err(EncryptionError::Authentication)
+        ));
+        assert!(matches!(
+            decrypt(&key, 8, &encrypted),
+            Err(EncryptionError::Authentication)
+        ));
+
+        let mut modified = encrypted.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            decrypt(&key, 7, &modified),
+            Err(EncryptionError::Authentication)
+        ));
+        assert!(matches!(
+            decrypt(&key, 7, b"input"),
+            Err(EncryptionError::InvalidFormat)
+        ));
+    }
+
+    #[test]
+    fn test_key_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()).unwrap(), key);
+        assert_eq!(Key::from_hex(&format!("{}\n", key.to_hex())).unwrap(), key);
+        assert!(Key::from_hex("abc").is_err());
+        assert!(Key::from_hex(&"zz".repeat(32)).is_err());
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * Random puzzle inputs for property and differential tests.
+ * Each day can provide `pub fn generate(rng: &mut Rng, size: usize) -> String` producing a well-formed input,
+ * where `size` scales the input (lines, monkeys, sensors, ...).
+ * Print one with `cargo solve <day> -- --generate [--seed N] [--size N]`.
+ */
+use std::panic::{self, AssertUnwindSafe};
+
+/// A small, seedable pseudo random number generator (SplitMix64).
+/// Generated inputs only depend on the seed, so failures can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..bound`. `bound` must not be zero.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "empty range");
+        self.next_u64() % bound
+    }
+
+    /// A number in `min..=max`.
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        assert!(min <= max, "empty range {}..={}", min, max);
+        min + self.below((max - min) as u64 + 1) as i64
+    }
+
+    /// An index in `0..len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// `true` with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64 > 1.0 - probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Generator settings parsed from the command line of a solution binary.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub seed: u64,
+    pub size: usize,
+}
+
+impl Options {
+    /// Parses `--generate [--seed N] [--size N]`. Returns `Ok(None)` if `--generate` was not passed.
+    pub fn from_env() -> Result<Option<Options>, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        if !args.contains("--generate") {
+            return Ok(None);
+        }
+
+        Ok(Some(Options {
+            seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+            size: args.opt_value_from_str("--size")?.unwrap_or(10),
+        }))
+    }
+}
+
+/// Prints a generated input if `--generate` was passed. Returns whether it did.
+pub fn print_if_requested(generate: fn(&mut Rng, usize) -> String) -> bool {
+    match Options::from_env().unwrap() {
+        Some(options) => {
+            print!("{}", generate(&mut Rng::new(options.seed), options.size));
+            true
+        }
+        None => false,
+    }
+}
+
+/// Runs `property` against `cases` generated inputs with sizes growing from 1 to `max_size`.
+/// If a case fails, the seed, size and input are printed before the panic is passed on.
+pub fn check(
+    cases: usize,
+    max_size: usize,
+    generate: fn(&mut Rng, usize) -> String,
+    property: impl Fn(&str),
+) {
+    for case in 0..cases {
+        let seed = case as u64;
+        let size = 1 + case * max_size.saturating_sub(1) / cases.max(1);
+        let input = generate(&mut Rng::new(seed), size);
+
+        if let Err(panic) = panic::catch_unwind(AssertUnwindSafe(|| property(&input))) {
+            eprintln!(
+                "property failed for seed {} and size {} (reproduce with `--generate --seed {} --size {}`):\n{}",
+                seed, size, seed, size, input
+            );
+            panic::resume_unwind(panic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let first: Vec<u64> = (0..5)
+            .map({
+                let mut rng = Rng::new(42);
+                move |_| rng.next_u64()
+            })
+            .collect();
+        let mut rng = Rng::new(42);
+        assert!(first.iter().all(|value| *value == rng.next_u64()));
+    }
+
+    #[test]
+    fn test_rng_ranges() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let value = rng.range(-3, 3);
+            assert!((-3..=3).contains(&value));
+        }
+        let mut items = [1, 2, 3, 4, 5];
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_check_reports_sizes() {
+        check(
+            10,
+            5,
+            |_, size| "x".repeat(size),
+            |input| assert!((1..=5).contains(&input.len())),
+        );
+    }
+}
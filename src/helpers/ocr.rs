@@ -0,0 +1,213 @@
+/*
+ * Reads letters drawn as pixel grids, as printed by several puzzles (e.g. 2022 day 10).
+ * Supports the common 4x6 font and the larger 6x10 font.
+ */
+use super::parse::ParseError;
+
+struct Font {
+    width: usize,
+    height: usize,
+    /// Columns between the start of two letters.
+    pitch: usize,
+    glyphs: &'static [(char, &'static str)],
+}
+
+const SMALL_FONT: Font = Font {
+    width: 4,
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', ".##.#..##..######..##..#"),
+        ('B', "###.#..####.#..##..####."),
+        ('C', ".##.#..##...#...#..#.##."),
+        ('E', "#####...###.#...#...####"),
+        ('F', "#####...###.#...#...#..."),
+        ('G', ".##.#..##...#.###..#.###"),
+        ('H', "#..##..######..##..##..#"),
+        ('I', ".###..#...#...#...#..###"),
+        ('J', "..##...#...#...##..#.##."),
+        ('K', "#..##.#.##..#.#.#.#.#..#"),
+        ('L', "#...#...#...#...#...####"),
+        ('O', ".##.#..##..##..##..#.##."),
+        ('P', "###.#..##..####.#...#..."),
+        ('R', "###.#..##..####.#.#.#..#"),
+        ('S', ".####...#....##....####."),
+        ('U', "#..##..##..##..##..#.##."),
+        ('Z', "####...#..#..#..#...####"),
+    ],
+};
+
+const LARGE_FONT: Font = Font {
+    width: 6,
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##...#..#.#....##....##....########....##....##....##....#",
+        ),
+        (
+            'B',
+            "#####.#....##....##....######.#....##....##....##....######.",
+        ),
+        (
+            'C',
+            ".####.#....##.....#.....#.....#.....#.....#.....#....#.####.",
+        ),
+        (
+            'E',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....######",
+        ),
+        (
+            'F',
+            "#######.....#.....#.....#####.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'G',
+            ".####.#....##.....#.....#.....#..####....##....##...##.###.#",
+        ),
+        (
+            'H',
+            "#....##....##....##....########....##....##....##....##....#",
+        ),
+        (
+            'J',
+            "...###....#.....#.....#.....#.....#.....#.#...#.#...#..###..",
+        ),
+        (
+            'K',
+            "#....##...#.#..#..#.#...##....##....#.#...#..#..#...#.#....#",
+        ),
+        (
+            'L',
+            "#.....#.....#.....#.....#.....#.....#.....#.....#.....######",
+        ),
+        (
+            'N',
+            "#....###...###...##.#..##.#..##..#.##..#.##...###...###....#",
+        ),
+        (
+            'P',
+            "#####.#....##....##....######.#.....#.....#.....#.....#.....",
+        ),
+        (
+            'R',
+            "#####.#....##....##....######.#..#..#...#.#...#.#....##....#",
+        ),
+        (
+            'X',
+            "#....##....#.#..#..#..#...##....##...#..#..#..#.#....##....#",
+        ),
+        (
+            'Z',
+            "######.....#.....#....#....#....#....#....#.....#.....######",
+        ),
+    ],
+};
+
+/// Reads the letters of a grid of lit (`true`) and dark pixels.
+/// The font is picked by the grid height (6 or 10 rows).
+pub fn recognize(grid: &[Vec<bool>]) -> Result<String, ParseError> {
+    let font = match grid.len() {
+        6 => &SMALL_FONT,
+        10 => &LARGE_FONT,
+        height => {
+            return Err(ParseError::new(format!(
+                "no font with a height of {} pixels",
+                height
+            )))
+        }
+    };
+
+    let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| grid[y].get(x).copied().unwrap_or(false);
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        let glyph: String = (0..font.height)
+            .flat_map(|y| (x..x + font.width).map(move |x| (x, y)))
+            .map(|(x, y)| if lit(x, y) { '#' } else { '.' })
+            .collect();
+
+        match font.glyphs.iter().find(|(_, pattern)| *pattern == glyph) {
+            Some((letter, _)) => letters.push(*letter),
+            None if !glyph.contains('#') => {}
+            None => {
+                let rows: Vec<&str> = (0..font.height)
+                    .map(|y| &glyph[y * font.width..(y + 1) * font.width])
+                    .collect();
+                return Err(ParseError::new(format!(
+                    "unknown glyph at column {}:\n{}",
+                    x,
+                    rows.join("\n")
+                )));
+            }
+        }
+        x += font.pitch;
+    }
+
+    if letters.is_empty() {
+        return Err(ParseError::new("grid does not contain any letters"));
+    }
+    Ok(letters)
+}
+
+/// Like [`recognize`], for grids drawn with `#` (lit) and any other character (dark).
+pub fn recognize_str(drawing: &str) -> Result<String, ParseError> {
+    let grid: Vec<Vec<bool>> = drawing
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    recognize(&grid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fonts_are_consistent() {
+        for font in [&SMALL_FONT, &LARGE_FONT] {
+            for (letter, pattern) in font.glyphs {
+                assert_eq!(pattern.len(), font.width * font.height, "glyph {}", letter);
+            }
+        }
+    }
+
+    #[test]
+    fn test_recognize_small() {
+        let drawing = "\
+###..#..#.####.#....
+#..#.#..#.#....#....
+#..#.####.###..#....
+###..#..#.#....#....
+#.#..#..#.#....#....
+#..#.#..#.####.####.";
+        assert_eq!(recognize_str(drawing), Ok("RHEL".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_large() {
+        let drawing = "\
+#....#..######
+#....#.......#
+#....#.......#
+#....#......#.
+######.....#..
+#....#....#...
+#....#...#....
+#....#..#.....
+#....#..#.....
+#....#..######";
+        assert_eq!(recognize_str(drawing), Ok("HZ".to_string()));
+    }
+
+    #[test]
+    fn test_recognize_errors() {
+        assert!(recognize_str("#\n#").is_err());
+
+        let error = recognize_str("####\n####\n####\n####\n####\n####").unwrap_err();
+        assert!(error.message().starts_with("unknown glyph at column 0"));
+    }
+}
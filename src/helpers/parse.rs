@@ -0,0 +1,270 @@
+/*
+ * Small parsing toolkit shared by the solutions.
+ * Example: `let (count, from, to) = scan!("move {} from {} to {}", line => u32, usize, usize)?;`
+ */
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Error returned by the parsing helpers.
+/// Carries the (1-based) input line it originated from once that is known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            line: None,
+            message: message.into(),
+        }
+    }
+
+    /// Attaches a line number. An already attached (more specific) line number is kept.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Wraps the error (including its line number) with some context, e.g. the block it occurred in.
+    pub fn context(self, context: impl Display) -> Self {
+        ParseError::new(format!("{}: {}", context, self))
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "line {}: {}", line, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Matches `input` against a pattern where every `{}` captures the text between its neighbouring literals.
+/// Prefer the [`scan!`](crate::scan) macro, which also parses the captures.
+pub fn scan<'a>(pattern: &str, input: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let mut literals = pattern.split("{}");
+    // `split` always yields at least one element.
+    let prefix = literals.next().unwrap();
+    let literals: Vec<&str> = literals.collect();
+
+    let mut rest = input.strip_prefix(prefix).ok_or_else(|| {
+        ParseError::new(format!("expected `{}` at the start of `{}`", prefix, input))
+    })?;
+
+    let mut captures = Vec::with_capacity(literals.len());
+    for (i, literal) in literals.iter().enumerate() {
+        let is_last = i == literals.len() - 1;
+        let end = if is_last {
+            rest.strip_suffix(literal).map(str::len)
+        } else if literal.is_empty() {
+            return Err(ParseError::new(format!(
+                "pattern `{}` has adjacent placeholders",
+                pattern
+            )));
+        } else {
+            rest.find(literal)
+        };
+
+        let end =
+            end.ok_or_else(|| ParseError::new(format!("expected `{}` in `{}`", literal, input)))?;
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    Ok(captures)
+}
+
+/// Parses a single capture produced by [`scan`]. Used by the [`scan!`](crate::scan) macro.
+pub fn parse_capture<T>(capture: Option<&str>, input: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let capture = capture.ok_or_else(|| {
+        ParseError::new(format!("pattern has too few placeholders for `{}`", input))
+    })?;
+    capture.parse().map_err(|e| {
+        ParseError::new(format!(
+            "could not parse `{}` as {} in `{}`: {}",
+            capture,
+            std::any::type_name::<T>(),
+            input,
+            e
+        ))
+    })
+}
+
+/// Matches a line against a `{}` pattern and parses every capture into the listed types.
+/// Evaluates to `Result<(T1, T2, ...), ParseError>`.
+#[macro_export]
+macro_rules! scan {
+    ($pattern:expr, $input:expr => $($ty:ty),+ $(,)?) => {{
+        (|| -> Result<_, $crate::helpers::parse::ParseError> {
+            let input: &str = $input;
+            let mut captures = $crate::helpers::parse::scan($pattern, input)?.into_iter();
+            let parsed = ($($crate::helpers::parse::parse_capture::<$ty>(captures.next(), input)?,)+);
+            match captures.next() {
+                Some(_) => Err($crate::helpers::parse::ParseError::new(format!(
+                    "pattern `{}` has more placeholders than types",
+                    $pattern
+                ))),
+                None => Ok(parsed),
+            }
+        })()
+    }};
+}
+
+/// Extracts every (optionally negative) integer contained in `line`, in order.
+/// A `-` only counts as a sign when it does not follow a digit or letter.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        // a dash directly after a digit is a separator (e.g. `2-4`), not a sign.
+        let negative = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        i += 1;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        result.push(parse_capture(Some(&line[start..i]), line)?);
+    }
+
+    Ok(result)
+}
+
+/// Splits the input into groups of lines separated by one or more blank lines.
+/// Tolerates `\r\n` line endings and leading or trailing blank lines.
+pub fn blocks(input: &str) -> Blocks<'_> {
+    Blocks { rest: input }
+}
+
+pub struct Blocks<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+
+        for line in self.rest.split_inclusive('\n') {
+            let line_start = offset;
+            offset += line.len();
+
+            if line.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(line_start);
+                end = line_start + line.trim_end_matches(['\r', '\n']).len();
+            }
+        }
+
+        let block = start.map(|start| &self.rest[start..end]);
+        self.rest = &self.rest[offset..];
+        block
+    }
+}
+
+/// Parses every line with `parser`, attaching the line number to any error.
+pub fn parse_lines<T>(
+    input: &str,
+    parser: impl Fn(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parser(line).map_err(|e| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            crate::scan!("move {} from {} to {}", "move 3 from 1 to 12" => u32, usize, usize),
+            Ok((3, 1, 12))
+        );
+        assert_eq!(crate::scan!("{} {}", "R 4" => char, i32), Ok(('R', 4)));
+        assert_eq!(
+            crate::scan!("Test: divisible by {}", "Test: divisible by 23" => i64),
+            Ok((23,))
+        );
+    }
+
+    #[test]
+    fn test_scan_errors() {
+        let error = crate::scan!("move {} from {} to {}", "move 3 frm 1 to 2" => u32, u32, u32)
+            .unwrap_err();
+        assert_eq!(error.message(), "expected ` from ` in `move 3 frm 1 to 2`");
+
+        let error = crate::scan!("move {}", "move x" => u32).unwrap_err();
+        assert!(error.message().starts_with("could not parse `x` as u32"));
+
+        let error = crate::scan!("{} {}", "a b" => char).unwrap_err();
+        assert!(error.message().contains("more placeholders than types"));
+    }
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(
+            ints::<i32>("Sensor at x=2, y=-18: closest beacon is at x=-2, y=15"),
+            Ok(vec![2, -18, -2, 15])
+        );
+        assert_eq!(ints::<u32>("1-2,3-4"), Ok(vec![1, 2, 3, 4]));
+        assert!(ints::<u8>("300").is_err());
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\n  a\nb\n\n\n c\r\n\r\nd\n";
+        assert_eq!(blocks(input).collect::<Vec<_>>(), vec!["  a\nb", " c", "d"]);
+        assert_eq!(blocks("").count(), 0);
+    }
+
+    #[test]
+    fn test_context() {
+        let error = ParseError::new("bad").at_line(2).context("monkey 1");
+        assert_eq!(error.to_string(), "monkey 1: line 2: bad");
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let error = parse_lines("1\n2\nx", |line| crate::scan!("{}", line => u32)).unwrap_err();
+        assert_eq!(error.line(), Some(3));
+    }
+}
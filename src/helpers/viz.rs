@@ -0,0 +1,373 @@
+/*
+ * Frame recorder for visualizing simulations.
+ * Solutions push frames into a `Recorder`, which can be played back in the terminal or exported as images.
+ * Example: `cargo solve 14 -- --visualize gif --part 2 --every 50`.
+ */
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+pub mod gif;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+/// A rectangular image whose top-left pixel sits at `origin` in simulation coordinates.
+/// Frames of one recording may cover different areas; exports place them on a common canvas.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    origin: (i64, i64),
+    width: usize,
+    height: usize,
+    background: Rgb,
+    pixels: Vec<Rgb>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Frame::with_origin((0, 0), width, height, background)
+    }
+
+    pub fn with_origin(origin: (i64, i64), width: usize, height: usize, background: Rgb) -> Self {
+        Frame {
+            origin,
+            width,
+            height,
+            background,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Sets the pixel at simulation coordinates `(x, y)`. Pixels outside the frame are ignored.
+    pub fn set(&mut self, x: i64, y: i64, color: Rgb) {
+        if let Some(index) = self.index(x, y) {
+            self.pixels[index] = color;
+        }
+    }
+
+    pub fn get(&self, x: i64, y: i64) -> Option<Rgb> {
+        self.index(x, y).map(|index| self.pixels[index])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, x: i64, y: i64) -> Option<usize> {
+        let (x, y) = (x - self.origin.0, y - self.origin.1);
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Rgb]> {
+        self.pixels.chunks(self.width.max(1))
+    }
+
+    /// Copies the frame onto a canvas covering `bounds` (`(min_x, min_y, max_x, max_y)`, inclusive).
+    fn placed(&self, bounds: (i64, i64, i64, i64)) -> Frame {
+        let (min_x, min_y, max_x, max_y) = bounds;
+        let width = (max_x - min_x + 1) as usize;
+        let height = (max_y - min_y + 1) as usize;
+        let mut canvas = Frame::with_origin((min_x, min_y), width, height, self.background);
+
+        for (row, pixels) in self.rows().enumerate() {
+            for (column, color) in pixels.iter().enumerate() {
+                canvas.set(
+                    self.origin.0 + column as i64,
+                    self.origin.1 + row as i64,
+                    *color,
+                );
+            }
+        }
+        canvas
+    }
+
+    fn scaled(&self, scale: usize) -> Frame {
+        if scale <= 1 {
+            return self.clone();
+        }
+
+        let mut pixels = Vec::with_capacity(self.pixels.len() * scale * scale);
+        for row in self.rows() {
+            let scaled_row: Vec<Rgb> = row
+                .iter()
+                .flat_map(|color| std::iter::repeat_n(*color, scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&scaled_row);
+            }
+        }
+
+        Frame {
+            origin: self.origin,
+            width: self.width * scale,
+            height: self.height * scale,
+            background: self.background,
+            pixels,
+        }
+    }
+}
+
+/// Collects frames from a simulation.
+/// A disabled recorder never calls the frame closure, so recording costs nothing during normal runs.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    enabled: bool,
+    every: usize,
+    calls: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new() -> Self {
+        Recorder::sampled(1)
+    }
+
+    /// A recorder that only keeps every `every`-th frame pushed into it.
+    pub fn sampled(every: usize) -> Self {
+        Recorder {
+            enabled: true,
+            every: every.max(1),
+            calls: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Recorder::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record(&mut self, frame: impl FnOnce() -> Frame) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.calls.is_multiple_of(self.every) {
+            self.frames.push(frame());
+        }
+        self.calls += 1;
+    }
+
+    /// Records a frame regardless of sampling, e.g. the final state of a simulation.
+    pub fn record_always(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.enabled {
+            self.frames.push(frame());
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// All frames placed on a canvas that covers every frame.
+    pub fn normalized_frames(&self) -> Vec<Frame> {
+        let bounds = self.frames.iter().fold(None, |bounds, frame| {
+            let (x, y) = frame.origin;
+            let (max_x, max_y) = (x + frame.width as i64 - 1, y + frame.height as i64 - 1);
+            Some(match bounds {
+                None => (x, y, max_x, max_y),
+                Some((a, b, c, d)) => (x.min(a), y.min(b), max_x.max(c), max_y.max(d)),
+            })
+        });
+
+        match bounds {
+            Some(bounds) => self.frames.iter().map(|f| f.placed(bounds)).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Backend {
+    Terminal,
+    Ppm,
+    Gif,
+}
+
+/// Visualization settings parsed from the command line of a solution binary.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub backend: Backend,
+    pub part: u8,
+    pub fps: u32,
+    pub every: usize,
+    pub scale: usize,
+    pub out: Option<PathBuf>,
+}
+
+impl Options {
+    /// Parses `--visualize <terminal|ppm|gif> [--part N] [--fps N] [--every N] [--scale N] [--out PATH]`.
+    /// Returns `Ok(None)` if `--visualize` was not passed.
+    pub fn from_env() -> Result<Option<Options>, pico_args::Error> {
+        Options::from_args(pico_args::Arguments::from_env())
+    }
+
+    pub fn from_args(mut args: pico_args::Arguments) -> Result<Option<Options>, pico_args::Error> {
+        let backend: Option<String> = args.opt_value_from_str("--visualize")?;
+        let backend = match backend.as_deref() {
+            None => return Ok(None),
+            Some("terminal") => Backend::Terminal,
+            Some("ppm") => Backend::Ppm,
+            Some("gif") => Backend::Gif,
+            Some(other) => {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: format!("unknown backend `{}`, use terminal, ppm or gif", other),
+                })
+            }
+        };
+
+        Ok(Some(Options {
+            backend,
+            part: args.opt_value_from_str("--part")?.unwrap_or(1),
+            fps: args.opt_value_from_str("--fps")?.unwrap_or(30).max(1),
+            every: args.opt_value_from_str("--every")?.unwrap_or(1),
+            scale: args.opt_value_from_str("--scale")?.unwrap_or(4),
+            out: args.opt_value_from_str("--out")?,
+        }))
+    }
+
+    pub fn recorder(&self) -> Recorder {
+        Recorder::sampled(self.every)
+    }
+
+    /// Plays or writes the recorded frames with the selected backend.
+    pub fn export(&self, recorder: &Recorder, day: u8) -> io::Result<()> {
+        let frames = recorder.normalized_frames();
+        let default_name = format!("viz_{:02}_part{}", day, self.part);
+
+        match self.backend {
+            Backend::Terminal => play_terminal(&frames, self.fps),
+            Backend::Ppm => {
+                let dir = self
+                    .out
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(default_name));
+                write_ppm_sequence(&frames, &dir, self.scale)?;
+                println!("Wrote {} frames to \"{}\".", frames.len(), dir.display());
+                Ok(())
+            }
+            Backend::Gif => {
+                let path = self
+                    .out
+                    .clone()
+                    .unwrap_or_else(|| PathBuf::from(format!("{}.gif", default_name)));
+                let scaled: Vec<Frame> = frames.iter().map(|f| f.scaled(self.scale)).collect();
+                gif::write(&path, &scaled, self.fps)?;
+                println!("Wrote {} frames to \"{}\".", frames.len(), path.display());
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Renders a frame with ANSI true-color half blocks, two pixel rows per terminal line.
+pub fn render_ansi(frame: &Frame) -> String {
+    let rows: Vec<&[Rgb]> = frame.rows().collect();
+    let mut output = String::new();
+
+    for pair in rows.chunks(2) {
+        for (column, top) in pair[0].iter().enumerate() {
+            let bottom = pair.get(1).map_or(frame.background, |row| row[column]);
+            output.push_str(&format!(
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m▀",
+                top.0, top.1, top.2, bottom.0, bottom.1, bottom.2
+            ));
+        }
+        output.push_str("\x1b[0m\n");
+    }
+    output
+}
+
+pub fn play_terminal(frames: &[Frame], fps: u32) -> io::Result<()> {
+    let delay = Duration::from_secs_f64(1.0 / fps.max(1) as f64);
+    let mut stdout = io::stdout().lock();
+
+    write!(stdout, "\x1b[2J")?;
+    for frame in frames {
+        write!(stdout, "\x1b[H{}", render_ansi(frame))?;
+        stdout.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+pub fn write_ppm(frame: &Frame, path: &Path) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    write!(file, "P6\n{} {}\n255\n", frame.width, frame.height)?;
+    for color in &frame.pixels {
+        file.write_all(&[color.0, color.1, color.2])?;
+    }
+    file.flush()
+}
+
+/// Writes `frame_00000.ppm`, `frame_00001.ppm`, ... into `dir`.
+pub fn write_ppm_sequence(frames: &[Frame], dir: &Path, scale: usize) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (index, frame) in frames.iter().enumerate() {
+        write_ppm(
+            &frame.scaled(scale),
+            &dir.join(format!("frame_{:05}.ppm", index)),
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recorder_sampling() {
+        let mut disabled = Recorder::disabled();
+        disabled.record(|| panic!("disabled recorders must not render frames"));
+
+        let mut recorder = Recorder::sampled(3);
+        for _ in 0..7 {
+            recorder.record(|| Frame::new(1, 1, Rgb::BLACK));
+        }
+        assert_eq!(recorder.frames().len(), 3);
+    }
+
+    #[test]
+    fn test_normalized_frames() {
+        let mut recorder = Recorder::new();
+        recorder.record(|| {
+            let mut frame = Frame::with_origin((0, 0), 1, 1, Rgb::BLACK);
+            frame.set(0, 0, Rgb::WHITE);
+            frame
+        });
+        recorder.record(|| Frame::with_origin((-2, 1), 2, 2, Rgb::BLACK));
+
+        let frames = recorder.normalized_frames();
+        assert!(frames.iter().all(|f| f.width() == 3 && f.height() == 3));
+        assert_eq!(frames[0].get(0, 0), Some(Rgb::WHITE));
+        assert_eq!(frames[0].get(-2, 2), Some(Rgb::BLACK));
+    }
+
+    #[test]
+    fn test_scaled() {
+        let mut frame = Frame::new(2, 1, Rgb::BLACK);
+        frame.set(1, 0, Rgb::WHITE);
+        let scaled = frame.scaled(2);
+        assert_eq!((scaled.width(), scaled.height()), (4, 2));
+        assert_eq!(scaled.get(2, 1), Some(Rgb::WHITE));
+        assert_eq!(scaled.get(1, 1), Some(Rgb::BLACK));
+    }
+}
@@ -0,0 +1,288 @@
+/*
+ * Minimal animated GIF (GIF89a) encoder.
+ * All frames share one global palette, so a recording may use at most 256 distinct colors.
+ */
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use super::{Frame, Rgb};
+
+const MAX_CODE: u16 = 4096;
+
+pub fn write(path: &Path, frames: &[Frame], fps: u32) -> io::Result<()> {
+    let mut file = BufWriter::new(File::create(path)?);
+    file.write_all(&encode(frames, fps)?)?;
+    file.flush()
+}
+
+/// Encodes equally sized frames into a looping GIF.
+pub fn encode(frames: &[Frame], fps: u32) -> io::Result<Vec<u8>> {
+    let first = frames
+        .first()
+        .ok_or_else(|| invalid_input("cannot encode a GIF without frames"))?;
+    let (width, height) = (first.width, first.height);
+    if frames
+        .iter()
+        .any(|f| f.width != width || f.height != height)
+    {
+        return Err(invalid_input("all GIF frames must have the same size"));
+    }
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(invalid_input("frames are too large for a GIF"));
+    }
+
+    let palette = build_palette(frames)?;
+    // the color table holds 2^(table_bits + 1) entries.
+    let table_bits = (palette.len().max(2).next_power_of_two().trailing_zeros() - 1) as u8;
+    let min_code_size = (table_bits + 1).max(2);
+    // GIF delays are in hundredths of a second.
+    let delay = (100 / fps.max(1)).max(2) as u16;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(b"GIF89a");
+    out.extend_from_slice(&(width as u16).to_le_bytes());
+    out.extend_from_slice(&(height as u16).to_le_bytes());
+    out.extend_from_slice(&[0x80 | (table_bits << 4) | table_bits, 0, 0]);
+
+    let mut colors: Vec<(Rgb, u8)> = palette.iter().map(|(c, i)| (*c, *i)).collect();
+    colors.sort_by_key(|(_, index)| *index);
+    for index in 0..(2usize << table_bits) {
+        let color = colors.get(index).map_or(Rgb::BLACK, |(color, _)| *color);
+        out.extend_from_slice(&[color.0, color.1, color.2]);
+    }
+
+    // NETSCAPE2.0 application extension: loop forever.
+    out.extend_from_slice(&[0x21, 0xFF, 0x0B]);
+    out.extend_from_slice(b"NETSCAPE2.0");
+    out.extend_from_slice(&[0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for frame in frames {
+        // graphic control extension with the frame delay.
+        out.extend_from_slice(&[0x21, 0xF9, 0x04, 0x00]);
+        out.extend_from_slice(&delay.to_le_bytes());
+        out.extend_from_slice(&[0x00, 0x00]);
+
+        // image descriptor covering the whole canvas, no local color table.
+        out.push(0x2C);
+        out.extend_from_slice(&[0, 0, 0, 0]);
+        out.extend_from_slice(&(width as u16).to_le_bytes());
+        out.extend_from_slice(&(height as u16).to_le_bytes());
+        out.push(0x00);
+
+        let indices: Vec<u8> = frame.pixels.iter().map(|c| palette[c]).collect();
+        out.push(min_code_size);
+        for block in lzw_encode(&indices, min_code_size).chunks(255) {
+            out.push(block.len() as u8);
+            out.extend_from_slice(block);
+        }
+        out.push(0x00);
+    }
+
+    out.push(0x3B);
+    Ok(out)
+}
+
+fn invalid_input(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+fn build_palette(frames: &[Frame]) -> io::Result<HashMap<Rgb, u8>> {
+    let mut palette = HashMap::new();
+    for color in frames.iter().flat_map(|f| f.pixels.iter()) {
+        if !palette.contains_key(color) {
+            if palette.len() == 256 {
+                return Err(invalid_input("GIF frames may use at most 256 colors"));
+            }
+            palette.insert(*color, palette.len() as u8);
+        }
+    }
+    Ok(palette)
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += size;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+/// Variable-length LZW as used by GIF: codes grow up to 12 bits, the table is reset when it is full.
+fn lzw_encode(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear_code = 1u16 << min_code_size;
+    let end_code = clear_code + 1;
+
+    let mut writer = BitWriter {
+        bytes: Vec::new(),
+        buffer: 0,
+        bits: 0,
+    };
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut code_size = min_code_size + 1;
+    let mut next_code = end_code + 1;
+
+    writer.write(clear_code, code_size);
+
+    let mut indices = indices.iter();
+    let mut current = match indices.next() {
+        Some(index) => *index as u16,
+        None => {
+            writer.write(end_code, code_size);
+            return writer.finish();
+        }
+    };
+
+    for &index in indices {
+        if let Some(&code) = table.get(&(current, index)) {
+            current = code;
+            continue;
+        }
+
+        writer.write(current, code_size);
+        if next_code == MAX_CODE {
+            writer.write(clear_code, code_size);
+            table.clear();
+            code_size = min_code_size + 1;
+            next_code = end_code + 1;
+        } else {
+            if next_code >= 1 << code_size {
+                code_size += 1;
+            }
+            table.insert((current, index), next_code);
+            next_code += 1;
+        }
+        current = index as u16;
+    }
+
+    writer.write(current, code_size);
+    // the decoder adds a table entry for the last code too, so it may already read the end code one bit wider.
+    if next_code >= 1 << code_size && code_size < 12 {
+        code_size += 1;
+    }
+    writer.write(end_code, code_size);
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference GIF LZW decoder used to check the encoder.
+    fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear_code = 1usize << min_code_size;
+        let end_code = clear_code + 1;
+        let reset = || -> Vec<Vec<u8>> { (0..clear_code).map(|i| vec![i as u8]).collect() };
+
+        let mut table = reset();
+        let mut code_size = min_code_size + 1;
+        let (mut buffer, mut bits, mut position) = (0u32, 0u8, 0);
+        let mut previous: Option<Vec<u8>> = None;
+        let mut output = Vec::new();
+
+        loop {
+            while bits < code_size {
+                buffer |= (data[position] as u32) << bits;
+                position += 1;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as usize;
+            buffer >>= code_size;
+            bits -= code_size;
+
+            if code == clear_code {
+                table = reset();
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end_code {
+                assert!(
+                    position == data.len() && bits < 8,
+                    "end code read before the end of the data"
+                );
+                return output;
+            }
+
+            // table indices skip the clear and end codes.
+            let lookup = |code: usize| if code < clear_code { code } else { code - 2 };
+            let entry = match &previous {
+                Some(prev) if lookup(code) >= table.len() => {
+                    let mut entry = prev.clone();
+                    entry.push(prev[0]);
+                    entry
+                }
+                _ => table[lookup(code)].clone(),
+            };
+            if let Some(mut prev) = previous.take() {
+                prev.push(entry[0]);
+                table.push(prev);
+                if table.len() + 2 == 1 << code_size && code_size < 12 {
+                    code_size += 1;
+                }
+            }
+            output.extend_from_slice(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn test_lzw_round_trip() {
+        let mut seed = 7u32;
+        let noisy: Vec<u8> = (0..20000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                (seed >> 16) as u8 % 5
+            })
+            .collect();
+        let runs: Vec<u8> = (0..10000).map(|i| (i / 37 % 3) as u8).collect();
+
+        for (data, min_code_size) in [(noisy, 3), (runs, 2), (vec![1], 2), (vec![], 2)] {
+            assert_eq!(
+                lzw_decode(&lzw_encode(&data, min_code_size), min_code_size),
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn test_lzw_ends_on_code_size_boundary() {
+        // every prefix length puts the last pixel code at a different point of the code table,
+        // including right where the code size grows.
+        let runs: Vec<u8> = (0..600).map(|i| (i / 3 % 4) as u8).collect();
+        for length in 0..runs.len() {
+            let data = &runs[..length];
+            assert_eq!(lzw_decode(&lzw_encode(data, 2), 2), data);
+        }
+    }
+
+    #[test]
+    fn test_encode() {
+        let mut frame = Frame::new(3, 2, Rgb::BLACK);
+        frame.set(1, 1, Rgb::WHITE);
+        let gif = encode(&[frame.clone(), frame], 10).unwrap();
+
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(&gif[6..10], &[3, 0, 2, 0]);
+        assert_eq!(gif.last(), Some(&0x3B));
+        assert!(encode(&[], 10).is_err());
+    }
+}
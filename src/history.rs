@@ -0,0 +1,172 @@
+/*
+ * A history of past runs per day and part, stored as JSON in `src/history.json`.
+ * Used by the dashboard to spot answers that changed and parts that became slower.
+ */
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::answer::Answer;
+
+/// Only the most recent runs of each part are kept.
+const MAX_RUNS: usize = 20;
+
+/// A run counts as slower if it takes this many times the median of the previous runs.
+const SLOWDOWN_FACTOR: f64 = 1.5;
+
+/// Faster runs are too noisy to compare.
+const MIN_COMPARED_TIME: Duration = Duration::from_micros(100);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Run {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub answer: Option<Answer>,
+    pub elapsed_ns: u64,
+}
+
+impl Run {
+    pub fn new(answer: Option<Answer>, elapsed: Duration) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        Run {
+            timestamp,
+            answer,
+            elapsed_ns: elapsed.as_nanos() as u64,
+        }
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns)
+    }
+}
+
+/// How the latest run of a part compares to the runs before it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Regression {
+    AnswerChanged { previous: Option<Answer> },
+    Slower { ratio: f64 },
+}
+
+#[derive(Debug, Default)]
+pub struct History {
+    path: PathBuf,
+    days: BTreeMap<u8, BTreeMap<u8, Vec<Run>>>,
+}
+
+impl History {
+    pub fn default_path() -> PathBuf {
+        PathBuf::from("src").join("history.json")
+    }
+
+    /// Loads the history, starting with an empty one if the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let days = match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+
+        Ok(History {
+            path: path.to_path_buf(),
+            days,
+        })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(&self.days)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(&self.path, json + "\n")
+    }
+
+    /// All stored runs of a part, oldest first.
+    pub fn runs(&self, day: u8, part: u8) -> &[Run] {
+        self.days
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    pub fn latest(&self, day: u8, part: u8) -> Option<&Run> {
+        self.runs(day, part).last()
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, run: Run) {
+        let runs = self.days.entry(day).or_default().entry(part).or_default();
+        runs.push(run);
+        if runs.len() > MAX_RUNS {
+            runs.drain(..runs.len() - MAX_RUNS);
+        }
+    }
+
+    /// Compares the latest run of a part with the ones before it.
+    pub fn regression(&self, day: u8, part: u8) -> Option<Regression> {
+        let (latest, previous) = self.runs(day, part).split_last()?;
+        let last = previous.last()?;
+        if last.answer != latest.answer {
+            return Some(Regression::AnswerChanged {
+                previous: last.answer.clone(),
+            });
+        }
+
+        let mut times: Vec<u64> = previous.iter().map(|run| run.elapsed_ns).collect();
+        times.sort_unstable();
+        let median = Duration::from_nanos(times[times.len() / 2]);
+        let ratio = latest.elapsed().as_secs_f64() / median.as_secs_f64().max(f64::EPSILON);
+        (latest.elapsed() >= MIN_COMPARED_TIME && ratio >= SLOWDOWN_FACTOR)
+            .then_some(Regression::Slower { ratio })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(answer: i64, elapsed_ms: u64) -> Run {
+        Run {
+            timestamp: 0,
+            answer: Some(Answer::Integer(answer)),
+            elapsed_ns: elapsed_ms * 1_000_000,
+        }
+    }
+
+    #[test]
+    fn test_regressions() {
+        let mut history = History::default();
+        history.record(1, 1, run(42, 10));
+        assert_eq!(history.regression(1, 1), None);
+
+        history.record(1, 1, run(42, 11));
+        assert_eq!(history.regression(1, 1), None);
+
+        history.record(1, 1, run(42, 30));
+        assert!(matches!(
+            history.regression(1, 1),
+            Some(Regression::Slower { ratio }) if ratio > 2.0
+        ));
+
+        history.record(1, 1, run(43, 10));
+        assert_eq!(
+            history.regression(1, 1),
+            Some(Regression::AnswerChanged {
+                previous: Some(Answer::Integer(42))
+            })
+        );
+    }
+
+    #[test]
+    fn test_keeps_recent_runs() {
+        let mut history = History::default();
+        for elapsed in 0..(MAX_RUNS as u64 + 5) {
+            history.record(3, 2, run(1, elapsed));
+        }
+        assert_eq!(history.runs(3, 2).len(), MAX_RUNS);
+        assert_eq!(history.runs(3, 2)[0].elapsed_ns, 5_000_000);
+    }
+}
@@ -4,50 +4,280 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
+use std::fmt::Debug;
 use std::fs;
 
+pub mod answer;
+pub mod dashboard;
+pub mod days;
+pub mod encryption;
 pub mod helpers;
+pub mod history;
+pub mod params;
+pub mod progress;
+pub mod runner;
+pub mod scaling;
+pub mod service;
+
+pub use answer::Answer;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs a solution part, printing its answer and timing.
+/// The solver may return any `Option<T>` where `T: Into<Answer>`.
+/// The day is taken from the name of the solution binary.
+/// Days with [`params::Param`]s pass their resolved [`params::Params`] as a fourth argument.
+///
+/// Parts with several registered implementations run the first one, or all of them with `--variants`:
+/// `solve!(1, variants: PART_ONE_VARIANTS, input)`.
+///
+/// Days with a `parse` function run both parts at once on the parsed input, timing each phase separately:
+/// `solve!(parse => part_one, part_two, input)`.
 #[macro_export]
 macro_rules! solve {
     ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        let day: u8 = env!("CARGO_BIN_NAME")
+            .parse()
+            .expect("solutions live in binaries named after their day");
+        $crate::runner::run_part(day, $part, || $solver($input));
+    }};
+    ($part:expr, $solver:ident, $input:expr, $params:expr) => {{
+        let day: u8 = env!("CARGO_BIN_NAME")
+            .parse()
+            .expect("solutions live in binaries named after their day");
+        $crate::runner::run_part(day, $part, || $solver($input, $params));
+    }};
+    ($part:expr, variants: $variants:expr, $input:expr) => {{
+        let day: u8 = env!("CARGO_BIN_NAME")
+            .parse()
+            .expect("solutions live in binaries named after their day");
+        $crate::runner::run_variants(day, $part, $variants, $input);
+    }};
+    ($parse:ident => $part_one:ident, $part_two:ident, $input:expr) => {{
+        let day: u8 = env!("CARGO_BIN_NAME")
+            .parse()
+            .expect("solutions live in binaries named after their day");
+        $crate::runner::run_parsed(day, || $parse($input), |i| $part_one(i), |i| $part_two(i));
+    }};
+    ($parse:ident => $part_one:ident, $part_two:ident, $input:expr, $params:expr) => {{
+        let day: u8 = env!("CARGO_BIN_NAME")
+            .parse()
+            .expect("solutions live in binaries named after their day");
+        $crate::runner::run_parsed(
+            day,
+            || $parse($input),
+            |i| $part_one(i, $params),
+            |i| $part_two(i, $params),
+        );
     }};
 }
 
+/// Reads `src/{folder}/{day}.txt`. If it is missing or empty, `src/{folder}/{day}.txt.enc` is decrypted instead,
+/// see [`encryption`].
 pub fn read_file(folder: &str, day: u8) -> String {
     let cwd = env::current_dir().unwrap();
 
     let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let f = fs::read_to_string(&filepath);
+    match f {
+        Ok(contents) if !contents.is_empty() => contents,
+        _ => match encryption::read_encrypted(&filepath, day) {
+            Ok(Some(contents)) => contents,
+            Ok(None) => f.expect("could not open input file"),
+            Err(e) => panic!("could not decrypt input file: {}", e),
+        },
+    }
+}
+
+/// Like [`read_file`], but returns `None` when the file is missing or empty,
+/// or when it is only available encrypted and no key is configured.
+/// Panics if an encrypted file cannot be decrypted with the configured key.
+pub fn try_read_file(folder: &str, day: u8) -> Option<String> {
+    let cwd = env::current_dir().ok()?;
+    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+
+    let contents = match fs::read_to_string(&filepath) {
+        Ok(contents) if !contents.trim().is_empty() => contents,
+        _ => match encryption::read_encrypted(&filepath, day) {
+            Ok(contents) => contents?,
+            Err(encryption::EncryptionError::MissingKey) => return None,
+            Err(e) => panic!("could not decrypt input file: {}", e),
+        },
+    };
+    Some(contents).filter(|contents| !contents.trim().is_empty())
+}
+
+/// Whitespace variations of an input that every solution should handle like the original.
+pub fn input_variants(input: &str) -> Vec<(&'static str, String)> {
+    let trimmed = input.trim_end();
+    vec![
+        ("trimmed", trimmed.to_string()),
+        ("newline-terminated", format!("{}\n", trimmed)),
+        ("crlf", input.replace('\n', "\r\n")),
+        (
+            "crlf-terminated",
+            format!("{}\r\n", trimmed.replace('\n', "\r\n")),
+        ),
+    ]
+}
+
+/// Panics unless every variant returns the same answer for `input`.
+pub fn assert_variants_agree<I: ?Sized, T: PartialEq + Debug>(
+    variants: &[runner::Variant<I, T>],
+    input: &I,
+    context: &str,
+) {
+    let Some((reference_name, reference)) = variants.first() else {
+        return;
+    };
+    let expected = reference(input);
+    for (name, solver) in &variants[1..] {
+        assert_eq!(
+            solver(input),
+            expected,
+            "variant `{}` disagrees with `{}` on {}",
+            name,
+            reference_name,
+            context
+        );
+    }
+}
+
+/// Generates the standard tests for a day's `part_one` and `part_two`:
+/// - `test_part_one` / `test_part_two` compare the example against the declared answers.
+/// - `test_input_part_one` / `test_input_part_two` (only if `input` is declared) compare the real input,
+///   and are skipped when `src/inputs/{day}.txt` does not exist.
+/// - `test_input_variants` checks that trimmed and CRLF versions of the example yield the same answers.
+///
+/// Days with several implementations add `variants: (PART_ONE_VARIANTS, PART_TWO_VARIANTS),`;
+/// `test_variants_agree` then checks that all of them agree on the example and the real input.
+/// Days with a separate parse step add `parse: parse,` after the day; the parts then receive the parsed input.
+/// Days that declare parameters add `params: PARAMS,`; the example tests then
+/// receive the example values and the input tests the real ones.
+///
+/// Use it inside the test module of a solution in `src/days/`:
+/// ```ignore
+/// #[cfg(test)]
+/// mod tests {
+///     use super::*;
+///
+///     crate::test_day! {
+///         day: 1,
+///         example: (Some(24000), Some(45000)),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! test_day {
+    (
+        day: $day:expr,
+        $(parse: $parse:ident,)?
+        $(params: $params:expr,)?
+        $(variants: ($variants_one:expr, $variants_two:expr),)?
+        example: ($example_one:expr, $example_two:expr),
+        $(input: ($input_one:expr, $input_two:expr),)?
+    ) => {
+        $crate::test_day!(@variants $day, [$($parse)?], [$($variants_one, $variants_two)?]);
+        $crate::test_day!(
+            @tests $day,
+            [$($parse)?],
+            [$($params)?],
+            ($example_one, $example_two),
+            $(($input_one, $input_two))?
+        );
+    };
+    (
+        @tests $day:expr,
+        $parse:tt,
+        $params:tt,
+        ($example_one:expr, $example_two:expr),
+        $(($input_one:expr, $input_two:expr))?
+    ) => {
+        #[test]
+        fn test_part_one() {
+            let input = $crate::read_file("examples", $day);
+            assert_eq!($crate::test_day!(@call part_one, &input, example, $parse, $params), $example_one);
+        }
+
+        #[test]
+        fn test_part_two() {
+            let input = $crate::read_file("examples", $day);
+            assert_eq!($crate::test_day!(@call part_two, &input, example, $parse, $params), $example_two);
+        }
+
+        $(
+            #[test]
+            fn test_input_part_one() {
+                if let Some(input) = $crate::try_read_file("inputs", $day) {
+                    assert_eq!($crate::test_day!(@call part_one, &input, real, $parse, $params), $input_one);
+                }
+            }
+
+            #[test]
+            fn test_input_part_two() {
+                if let Some(input) = $crate::try_read_file("inputs", $day) {
+                    assert_eq!($crate::test_day!(@call part_two, &input, real, $parse, $params), $input_two);
+                }
+            }
+        )?
+
+        #[test]
+        fn test_input_variants() {
+            let input = $crate::read_file("examples", $day);
+            let expected_one = $crate::test_day!(@call part_one, &input, example, $parse, $params);
+            let expected_two = $crate::test_day!(@call part_two, &input, example, $parse, $params);
+
+            for (name, variant) in $crate::input_variants(&input) {
+                assert_eq!(
+                    $crate::test_day!(@call part_one, &variant, example, $parse, $params),
+                    expected_one,
+                    "part one differs for {} input",
+                    name
+                );
+                assert_eq!(
+                    $crate::test_day!(@call part_two, &variant, example, $parse, $params),
+                    expected_two,
+                    "part two differs for {} input",
+                    name
+                );
+            }
+        }
+    };
+    (@variants $day:expr, $parse:tt, []) => {};
+    (@variants $day:expr, $parse:tt, [$variants_one:expr, $variants_two:expr]) => {
+        #[test]
+        fn test_variants_agree() {
+            let mut inputs = vec![("the example", $crate::read_file("examples", $day))];
+            inputs.extend($crate::try_read_file("inputs", $day).map(|input| ("the input", input)));
+
+            for (name, input) in &inputs {
+                let input = $crate::test_day!(@parsed input, $parse);
+                $crate::assert_variants_agree($variants_one, input, &format!("part one of {}", name));
+                $crate::assert_variants_agree($variants_two, input, &format!("part two of {}", name));
+            }
+        }
+    };
+    (@parsed $input:expr, []) => {
+        $input.as_str()
+    };
+    (@parsed $input:expr, [$parse:ident]) => {
+        &$parse($input)
+    };
+    (@call $solver:ident, $input:expr, $kind:ident, [], []) => {
+        $solver($input)
+    };
+    (@call $solver:ident, $input:expr, $kind:ident, [], [$params:expr]) => {
+        $solver($input, &$crate::params::Params::$kind($params))
+    };
+    (@call $solver:ident, $input:expr, $kind:ident, [$parse:ident], []) => {
+        $solver(&$parse($input))
+    };
+    (@call $solver:ident, $input:expr, $kind:ident, [$parse:ident], [$params:expr]) => {
+        $solver(&$parse($input), &$crate::params::Params::$kind($params))
+    };
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
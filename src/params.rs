@@ -0,0 +1,125 @@
+/*
+ * Named puzzle parameters whose values differ between the example and the real input,
+ * e.g. the row to inspect on day 15.
+ * Declare them per day: `pub const PARAMS: &[Param] = &[Param::new("row", 10, 2_000_000)];`
+ */
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    pub example: i64,
+    pub real: i64,
+}
+
+impl Param {
+    pub const fn new(name: &'static str, example: i64, real: i64) -> Self {
+        Param {
+            name,
+            example,
+            real,
+        }
+    }
+}
+
+/// Resolved parameter values passed to a solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Params {
+    /// The values to use with the example input.
+    pub fn example(declared: &[Param]) -> Self {
+        Params {
+            values: declared.iter().map(|p| (p.name, p.example)).collect(),
+        }
+    }
+
+    /// The values to use with the real input.
+    pub fn real(declared: &[Param]) -> Self {
+        Params {
+            values: declared.iter().map(|p| (p.name, p.real)).collect(),
+        }
+    }
+
+    /// The real values, overridden by any `--param name=value` arguments.
+    pub fn from_env(declared: &[Param]) -> Result<Self, String> {
+        let mut args = pico_args::Arguments::from_env();
+        let overrides: Vec<String> = args
+            .values_from_str("--param")
+            .map_err(|e| e.to_string())?;
+        Params::real(declared).with_overrides(&overrides)
+    }
+
+    /// Applies overrides in the form `name=value`. Unknown names are rejected.
+    pub fn with_overrides(mut self, overrides: &[String]) -> Result<Self, String> {
+        for assignment in overrides {
+            let (name, value) = assignment
+                .split_once('=')
+                .ok_or_else(|| format!("expected `name=value`, got `{}`", assignment))?;
+            let value: i64 = value
+                .trim()
+                .parse()
+                .map_err(|e| format!("invalid value for parameter `{}`: {}", name, e))?;
+
+            match self.values.iter_mut().find(|(n, _)| *n == name.trim()) {
+                Some(entry) => entry.1 = value,
+                None => {
+                    let known: Vec<&str> = self.values.iter().map(|(n, _)| *n).collect();
+                    return Err(format!(
+                        "unknown parameter `{}`, known parameters: {}",
+                        name,
+                        known.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Returns the value of a declared parameter.
+    /// Panics if the parameter was not declared or does not fit into `T`.
+    pub fn get<T>(&self, name: &str) -> T
+    where
+        T: TryFrom<i64>,
+        T::Error: Display,
+    {
+        let value = self
+            .values
+            .iter()
+            .find(|(n, _)| *n == name)
+            .unwrap_or_else(|| panic!("parameter `{}` is not declared", name))
+            .1;
+        T::try_from(value).unwrap_or_else(|e| panic!("parameter `{}`: {}", name, e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param::new("row", 10, 2_000_000), Param::new("size", 20, 40)];
+
+    #[test]
+    fn test_example_and_real() {
+        assert_eq!(Params::example(PARAMS).get::<i32>("row"), 10);
+        assert_eq!(Params::real(PARAMS).get::<i32>("row"), 2_000_000);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let params = Params::real(PARAMS)
+            .with_overrides(&["row=7".to_string()])
+            .unwrap();
+        assert_eq!(params.get::<u8>("row"), 7);
+        assert_eq!(params.get::<u8>("size"), 40);
+
+        assert!(Params::real(PARAMS)
+            .with_overrides(&["column=7".to_string()])
+            .is_err());
+        assert!(Params::real(PARAMS)
+            .with_overrides(&["row".to_string()])
+            .is_err());
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Progress and benchmark tables for the readme, generated from the accepted answers and the run history.
+ * `cargo readme` rewrites the section between `PROGRESS_START` and `PROGRESS_END` in `README.md`.
+ */
+use std::time::Duration;
+
+use crate::answer::AnswerStore;
+use crate::history::History;
+
+pub const PROGRESS_START: &str = "<!--- progress start --->";
+pub const PROGRESS_END: &str = "<!--- progress end --->";
+
+/// The star table for every day with at least one accepted answer,
+/// followed by the benchmark table for every day with recorded runs.
+pub fn render(answers: &AnswerStore, history: &History) -> String {
+    let mut output = String::from("## Progress\n\n");
+
+    let solved: Vec<u8> = (1..=25)
+        .filter(|day| (1..=2).any(|part| answers.get(*day, part).is_some()))
+        .collect();
+    if solved.is_empty() {
+        output += "No stars yet.\n";
+    } else {
+        output += "| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n";
+        for day in &solved {
+            let star = |part| {
+                if answers.get(*day, part).is_some() {
+                    "⭐"
+                } else {
+                    " "
+                }
+            };
+            output += &format!("| {:02} | {} | {} |\n", day, star(1), star(2));
+        }
+        let stars: usize = (1..=25)
+            .map(|day| {
+                (1..=2)
+                    .filter(|part| answers.get(day, *part).is_some())
+                    .count()
+            })
+            .sum();
+        output += &format!("\n{} / 50 ⭐\n", stars);
+    }
+
+    let benchmarked: Vec<(u8, Option<Duration>, Option<Duration>)> = (1..=25)
+        .map(|day| {
+            let elapsed = |part| history.latest(day, part).map(|run| run.elapsed());
+            (day, elapsed(1), elapsed(2))
+        })
+        .filter(|(_, one, two)| one.is_some() || two.is_some())
+        .collect();
+    if !benchmarked.is_empty() {
+        output += "\n## Benchmarks\n\n";
+        output += "| Day | Part 1 | Part 2 | Total |\n| :---: | ---: | ---: | ---: |\n";
+        let format =
+            |time: Option<Duration>| time.map_or("-".to_string(), |t| format!("{:.2?}", t));
+        let mut total = Duration::ZERO;
+        for (day, one, two) in benchmarked {
+            let day_total = one.unwrap_or_default() + two.unwrap_or_default();
+            total += day_total;
+            output += &format!(
+                "| {:02} | {} | {} | {} |\n",
+                day,
+                format(one),
+                format(two),
+                format(Some(day_total))
+            );
+        }
+        output += &format!("| **Total** | | | **{}** |\n", format(Some(total)));
+    }
+    output
+}
+
+/// Replaces everything between the markers with `content`. Fails if a marker is missing.
+pub fn replace_section(readme: &str, content: &str) -> Result<String, String> {
+    let start = readme
+        .find(PROGRESS_START)
+        .ok_or_else(|| format!("missing marker `{}`", PROGRESS_START))?
+        + PROGRESS_START.len();
+    let end = readme[start..].find(PROGRESS_END).ok_or_else(|| {
+        format!(
+            "missing marker `{}` after `{}`",
+            PROGRESS_END, PROGRESS_START
+        )
+    })? + start;
+
+    Ok(format!(
+        "{}\n\n{}\n\n{}",
+        &readme[..start],
+        content.trim_end(),
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::history::Run;
+
+    #[test]
+    fn test_render() {
+        let mut answers = AnswerStore::default();
+        answers.accept(1, 1, Answer::Integer(1));
+        answers.accept(1, 2, Answer::Integer(2));
+        answers.accept(3, 1, Answer::Integer(3));
+        let mut history = History::default();
+        history.record(1, 1, Run::new(None, Duration::from_millis(2)));
+        history.record(1, 2, Run::new(None, Duration::from_millis(3)));
+
+        let tables = render(&answers, &history);
+        assert!(tables.contains("| 01 | ⭐ | ⭐ |"));
+        assert!(tables.contains("| 03 | ⭐ |   |"));
+        assert!(!tables.contains("| 02 |"));
+        assert!(tables.contains("3 / 50 ⭐"));
+        assert!(tables.contains("| 01 | 2.00ms | 3.00ms | 5.00ms |"));
+        assert!(tables.contains("| **Total** | | | **5.00ms** |"));
+    }
+
+    #[test]
+    fn test_replace_section_is_idempotent() {
+        let readme = format!(
+            "# Title\n\n{}\nold\n{}\n\nrest\n",
+            PROGRESS_START, PROGRESS_END
+        );
+        let once = replace_section(&readme, "new table\n").unwrap();
+        assert_eq!(
+            once,
+            format!(
+                "# Title\n\n{}\n\nnew table\n\n{}\n\nrest\n",
+                PROGRESS_START, PROGRESS_END
+            )
+        );
+        assert_eq!(replace_section(&once, "new table\n").unwrap(), once);
+
+        assert!(replace_section("# Title\n", "table").is_err());
+    }
+}
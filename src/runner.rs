@@ -0,0 +1,258 @@
+/*
+ * Runs a solution part for the `solve!` macro: times it, prints the answer and checks it against the accepted one.
+ * Days with a separate `parse` step are run by `run_parsed`, which times parsing on its own.
+ * Days with several implementations of a part are run by `run_variants`; pass `--variants` to compare all of them.
+ * Input that is not the day's puzzle input is run by `run_unchecked_variants`, which leaves the accepted answers alone.
+ * Pass `--json` to print one JSON object per part, or `--accept` to store the current answers as accepted.
+ */
+use std::time::{Duration, Instant};
+
+use crate::answer::{Answer, AnswerStore};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// A named implementation of a part, e.g. `("naive", part_one_naive)`.
+/// Takes the raw input (`I = str`) or the parsed input of the day.
+pub type Variant<I, T> = (&'static str, fn(&I) -> Option<T>);
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    pub json: bool,
+    pub accept: bool,
+    pub variants: bool,
+}
+
+impl Options {
+    pub fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+        Options {
+            json: args.contains("--json"),
+            accept: args.contains("--accept"),
+            variants: args.contains("--variants"),
+        }
+    }
+}
+
+pub fn run_part<T: Into<Answer>>(day: u8, part: u8, solver: impl FnOnce() -> Option<T>) {
+    let options = Options::from_env();
+    let (answer, elapsed) = time_part(solver);
+    report(day, part, answer, elapsed, None, true, &options);
+}
+
+/// Parses the input once and runs both parts on the parsed input.
+/// Parsing, part one and part two are timed separately.
+pub fn run_parsed<I, A, B>(
+    day: u8,
+    parse: impl FnOnce() -> I,
+    part_one: impl FnOnce(&I) -> Option<A>,
+    part_two: impl FnOnce(&I) -> Option<B>,
+) where
+    A: Into<Answer>,
+    B: Into<Answer>,
+{
+    let options = Options::from_env();
+
+    let timer = Instant::now();
+    let parsed = parse();
+    let parse_elapsed = timer.elapsed();
+
+    if !options.json {
+        print_parse(parse_elapsed);
+    }
+
+    let (answer, elapsed) = time_part(|| part_one(&parsed));
+    report(day, 1, answer, elapsed, Some(parse_elapsed), true, &options);
+
+    let (answer, elapsed) = time_part(|| part_two(&parsed));
+    report(day, 2, answer, elapsed, Some(parse_elapsed), true, &options);
+}
+
+/// Runs the first (default) variant of a part like [`run_part`].
+/// With `--variants`, runs every variant side by side and reports whether they agree.
+pub fn run_variants<I: ?Sized, T: Into<Answer>>(
+    day: u8,
+    part: u8,
+    variants: &[Variant<I, T>],
+    input: &I,
+) {
+    variants_report(day, part, variants, input, true);
+}
+
+/// Runs the variants of a part like [`run_variants`] on input that is not the day's puzzle input,
+/// e.g. a generated benchmark. The answer is neither checked against nor stored as the accepted one.
+pub fn run_unchecked_variants<I: ?Sized, T: Into<Answer>>(
+    day: u8,
+    part: u8,
+    variants: &[Variant<I, T>],
+    input: &I,
+) {
+    variants_report(day, part, variants, input, false);
+}
+
+fn variants_report<I: ?Sized, T: Into<Answer>>(
+    day: u8,
+    part: u8,
+    variants: &[Variant<I, T>],
+    input: &I,
+    check: bool,
+) {
+    let options = Options::from_env();
+    let (_, default) = variants
+        .first()
+        .expect("at least one variant has to be registered");
+
+    if !options.variants {
+        let (answer, elapsed) = time_part(|| default(input));
+        report(day, part, answer, elapsed, None, check, &options);
+        return;
+    }
+
+    let results: Vec<(&str, Option<Answer>, Duration)> = variants
+        .iter()
+        .map(|(name, solver)| {
+            let (answer, elapsed) = time_part(|| solver(input));
+            (*name, answer, elapsed)
+        })
+        .collect();
+    let agree = results.iter().all(|(_, answer, _)| *answer == results[0].1);
+
+    if options.json {
+        for (name, answer, elapsed) in &results {
+            let output = serde_json::json!({
+                "day": day,
+                "part": part,
+                "variant": name,
+                "answer": answer,
+                "elapsed_ns": elapsed.as_nanos() as u64,
+                "agree": agree,
+            });
+            println!("{}", output);
+        }
+        return;
+    }
+
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    let width = results.iter().map(|(name, _, _)| name.len()).max().unwrap_or(0);
+    for (name, answer, elapsed) in &results {
+        let answer = answer
+            .as_ref()
+            .map_or_else(|| "not solved.".to_string(), Answer::to_string);
+        println!(
+            "{:width$}  {} {}(elapsed: {:.2?}){}",
+            name,
+            answer,
+            ANSI_ITALIC,
+            elapsed,
+            ANSI_RESET,
+            width = width
+        );
+    }
+    if !agree {
+        println!("✗ the variants disagree");
+    }
+}
+
+fn time_part<T: Into<Answer>>(solver: impl FnOnce() -> Option<T>) -> (Option<Answer>, Duration) {
+    let timer = Instant::now();
+    let result = solver();
+    let elapsed = timer.elapsed();
+    (result.map(Into::into), elapsed)
+}
+
+fn report(
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    elapsed: Duration,
+    parse_elapsed: Option<Duration>,
+    check: bool,
+    options: &Options,
+) {
+    let verified = answer
+        .as_ref()
+        .filter(|_| check)
+        .and_then(|answer| check_answer(day, part, answer, options.accept));
+
+    if options.json {
+        print_json(day, part, answer.as_ref(), elapsed, parse_elapsed, verified);
+    } else {
+        print_result(part, answer.as_ref(), elapsed, verified);
+    }
+}
+
+/// Compares the answer with the accepted one, storing it first if `accept` is set.
+fn check_answer(day: u8, part: u8, answer: &Answer, accept: bool) -> Option<bool> {
+    let mut store = match AnswerStore::load(&AnswerStore::default_path()) {
+        Ok(store) => store,
+        Err(e) => {
+            eprintln!("could not read accepted answers: {}", e);
+            return None;
+        }
+    };
+
+    if accept {
+        store.accept(day, part, answer.clone());
+        if let Err(e) = store.save() {
+            eprintln!("could not store accepted answer: {}", e);
+        }
+    }
+
+    store.verify(day, part, answer)
+}
+
+fn verification_status(verified: Option<bool>) -> &'static str {
+    match verified {
+        Some(true) => " ✓",
+        Some(false) => " ✗ (differs from the accepted answer)",
+        None => "",
+    }
+}
+
+fn print_result(part: u8, answer: Option<&Answer>, elapsed: Duration, verified: Option<bool>) {
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+
+    let status = verification_status(verified);
+    match answer {
+        Some(answer) if answer.is_multiline() => {
+            println!("{}", answer.to_block());
+            println!(
+                "{}(elapsed: {:.2?}){}{}",
+                ANSI_ITALIC, elapsed, ANSI_RESET, status
+            );
+        }
+        Some(answer) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}{}",
+                answer, ANSI_ITALIC, elapsed, ANSI_RESET, status
+            );
+        }
+        None => {
+            println!("not solved.")
+        }
+    }
+}
+
+fn print_parse(elapsed: Duration) {
+    println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    println!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, elapsed, ANSI_RESET);
+}
+
+fn print_json(
+    day: u8,
+    part: u8,
+    answer: Option<&Answer>,
+    elapsed: Duration,
+    parse_elapsed: Option<Duration>,
+    verified: Option<bool>,
+) {
+    let mut output = serde_json::json!({
+        "day": day,
+        "part": part,
+        "answer": answer,
+        "elapsed_ns": elapsed.as_nanos() as u64,
+        "verified": verified,
+    });
+    if let Some(parse_elapsed) = parse_elapsed {
+        output["parse_elapsed_ns"] = (parse_elapsed.as_nanos() as u64).into();
+    }
+    println!("{}", output);
+}
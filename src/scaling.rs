@@ -0,0 +1,203 @@
+/*
+ * Scaling benchmarks: runs both parts of a day on generated inputs of growing size
+ * and estimates the complexity exponent `k` of `time ~ n^k`, where `n` is the input length in bytes.
+ * Example: `cargo solve 12 --release -- --complexity --to 128`.
+ */
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::helpers::generate::Rng;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Each size is measured repeatedly until this much time has passed.
+const MEASURE_TIME: Duration = Duration::from_millis(100);
+
+/// Scaling settings parsed from the command line of a solution binary.
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub from: usize,
+    pub to: usize,
+    pub seed: u64,
+    /// Larger sizes are skipped for a part once a single run takes longer than this.
+    pub time_limit: Duration,
+}
+
+impl Options {
+    /// Parses `--complexity [--from N] [--to N] [--seed N] [--time-limit SECONDS]`.
+    /// Returns `Ok(None)` if `--complexity` was not passed.
+    pub fn from_env() -> Result<Option<Options>, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        if !args.contains("--complexity") {
+            return Ok(None);
+        }
+
+        Ok(Some(Options {
+            from: args.opt_value_from_str("--from")?.unwrap_or(4),
+            to: args.opt_value_from_str("--to")?.unwrap_or(256),
+            seed: args.opt_value_from_str("--seed")?.unwrap_or(0),
+            time_limit: Duration::from_secs_f64(
+                args.opt_value_from_str("--time-limit")?.unwrap_or(5.0),
+            ),
+        }))
+    }
+
+    /// Sizes doubling from `from` up to `to`.
+    pub fn sizes(&self) -> Vec<usize> {
+        std::iter::successors(Some(self.from.max(1)), |size| Some(size * 2))
+            .take_while(|size| *size <= self.to)
+            .collect()
+    }
+}
+
+/// One measured input size.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    pub size: usize,
+    pub bytes: usize,
+    /// Median time per part, `None` once the part exceeded the time limit.
+    pub times: [Option<Duration>; 2],
+}
+
+/// Runs the scaling benchmark if `--complexity` was passed. Returns whether it did.
+pub fn run_if_requested<A, B>(
+    generate: fn(&mut Rng, usize) -> String,
+    part_one: impl Fn(&str) -> A,
+    part_two: impl Fn(&str) -> B,
+) -> bool {
+    let Some(options) = Options::from_env().unwrap() else {
+        return false;
+    };
+
+    if cfg!(debug_assertions) {
+        println!("note: run with `--release` for meaningful timings.");
+    }
+    let samples = measure(&options, generate, &part_one, &part_two);
+    print_report(&samples);
+    true
+}
+
+pub fn measure<A, B>(
+    options: &Options,
+    generate: fn(&mut Rng, usize) -> String,
+    part_one: &impl Fn(&str) -> A,
+    part_two: &impl Fn(&str) -> B,
+) -> Vec<Sample> {
+    let mut samples: Vec<Sample> = Vec::new();
+
+    for size in options.sizes() {
+        let input = generate(&mut Rng::new(options.seed), size);
+        let within_limit = |part: usize| {
+            samples
+                .last()
+                .is_none_or(|last| last.times[part].is_some_and(|time| time <= options.time_limit))
+        };
+
+        let times = [
+            within_limit(0).then(|| median_time(|| drop(black_box(part_one(black_box(&input)))))),
+            within_limit(1).then(|| median_time(|| drop(black_box(part_two(black_box(&input)))))),
+        ];
+        samples.push(Sample {
+            size,
+            bytes: input.len(),
+            times,
+        });
+    }
+    samples
+}
+
+fn median_time(mut run: impl FnMut()) -> Duration {
+    let started = Instant::now();
+    let mut times = Vec::new();
+    while times.is_empty() || started.elapsed() < MEASURE_TIME {
+        let timer = Instant::now();
+        run();
+        times.push(timer.elapsed());
+    }
+    times.sort_unstable();
+    times[times.len() / 2]
+}
+
+/// Least squares fit of `log(time) = k * log(bytes) + c`. Returns `k`.
+/// Needs at least two samples of different input lengths.
+pub fn estimate_exponent(points: &[(usize, Duration)]) -> Option<f64> {
+    let logs: Vec<(f64, f64)> = points
+        .iter()
+        .map(|(bytes, time)| ((*bytes as f64).ln(), time.as_secs_f64().max(1e-9).ln()))
+        .collect();
+    let count = logs.len() as f64;
+    let mean_x = logs.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = logs.iter().map(|(_, y)| y).sum::<f64>() / count;
+
+    let covariance: f64 = logs.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+    let variance: f64 = logs.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    (variance > 0.0).then(|| covariance / variance)
+}
+
+fn print_report(samples: &[Sample]) {
+    println!("🎄 {}Scaling{} 🎄", ANSI_BOLD, ANSI_RESET);
+    println!(
+        "{:>8} {:>10} {:>12} {:>12}",
+        "size", "bytes", "part 1", "part 2"
+    );
+    for sample in samples {
+        let time =
+            |part: usize| sample.times[part].map_or("-".to_string(), |t| format!("{:.2?}", t));
+        println!(
+            "{:>8} {:>10} {:>12} {:>12}",
+            sample.size,
+            sample.bytes,
+            time(0),
+            time(1)
+        );
+    }
+
+    for part in 0..2 {
+        let points: Vec<(usize, Duration)> = samples
+            .iter()
+            .filter_map(|sample| sample.times[part].map(|time| (sample.bytes, time)))
+            .collect();
+        match estimate_exponent(&points) {
+            Some(exponent) => println!(
+                "Part {}: {}O(n^{:.2}){}{}",
+                part + 1,
+                ANSI_ITALIC,
+                exponent,
+                ANSI_RESET,
+                if exponent > 1.5 {
+                    " ⚠ superlinear"
+                } else {
+                    ""
+                }
+            ),
+            None => println!("Part {}: not enough samples.", part + 1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sizes() {
+        let options = Options {
+            from: 4,
+            to: 40,
+            seed: 0,
+            time_limit: Duration::from_secs(1),
+        };
+        assert_eq!(options.sizes(), vec![4, 8, 16, 32]);
+    }
+
+    #[test]
+    fn test_estimate_exponent() {
+        let quadratic: Vec<(usize, Duration)> = [10, 20, 40, 80]
+            .iter()
+            .map(|n| (*n, Duration::from_nanos((n * n) as u64 * 100)))
+            .collect();
+        let exponent = estimate_exponent(&quadratic).unwrap();
+        assert!((exponent - 2.0).abs() < 1e-6);
+
+        assert_eq!(estimate_exponent(&quadratic[..1]), None);
+    }
+}
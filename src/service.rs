@@ -0,0 +1,315 @@
+/*
+ * A small HTTP/JSON service on localhost that runs the registered solvers on posted inputs.
+ * - `GET /days` lists the available days and parts.
+ * - `POST /solve/{day}/{part}` runs a part on the request body and returns its answer and timing.
+ * Start it with `cargo serve [--port N] [--max-input-bytes N] [--timeout SECONDS]`.
+ */
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::days::{self, Solver};
+
+/// Requests with longer request lines or headers are rejected.
+const MAX_HEADER_BYTES: usize = 8 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub port: u16,
+    /// Larger request bodies are rejected with `413 Payload Too Large`.
+    pub max_input_bytes: usize,
+    /// Solvers running longer are abandoned with `504 Gateway Timeout`.
+    pub timeout: Duration,
+}
+
+impl Options {
+    pub fn from_env() -> Result<Self, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        Ok(Options {
+            port: args.opt_value_from_str("--port")?.unwrap_or(3030),
+            max_input_bytes: args
+                .opt_value_from_str("--max-input-bytes")?
+                .unwrap_or(1024 * 1024),
+            timeout: Duration::from_secs_f64(args.opt_value_from_str("--timeout")?.unwrap_or(10.0)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Response {
+    fn ok(body: Value) -> Self {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// Serves requests on `127.0.0.1`, one thread per connection.
+pub fn serve(options: &Options) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", options.port))?;
+    println!("Listening on http://{}", listener.local_addr()?);
+
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let options = options.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, &options) {
+                eprintln!("connection failed: {}", e);
+            }
+        });
+    }
+    Ok(())
+}
+
+fn handle_connection(stream: TcpStream, options: &Options) -> io::Result<()> {
+    // slow clients must not keep a connection open forever.
+    stream.set_read_timeout(Some(options.timeout))?;
+    let mut reader = BufReader::new(&stream);
+    let response = match read_request(&mut reader, options.max_input_bytes) {
+        Ok((method, path, body)) => handle(&method, &path, body, options),
+        Err(response) => response,
+    };
+    write_response(&mut &stream, &response)
+}
+
+/// Reads the method, path and body of a request, or the error response to send instead.
+fn read_request(
+    reader: &mut impl BufRead,
+    max_input_bytes: usize,
+) -> Result<(String, String, Vec<u8>), Response> {
+    let mut header_bytes = 0;
+    let mut read_line = || -> Result<String, Response> {
+        let mut line = String::new();
+        let read = reader
+            .by_ref()
+            .take((MAX_HEADER_BYTES - header_bytes) as u64)
+            .read_line(&mut line)
+            .map_err(|e| Response::error(400, format!("could not read request: {}", e)))?;
+        header_bytes += read;
+        if !line.ends_with('\n') {
+            return Err(Response::error(431, "request headers are too large"));
+        }
+        Ok(line.trim_end().to_string())
+    };
+
+    let request_line = read_line()?;
+    let mut parts = request_line.split(' ');
+    let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+    let (method, path) = (method.to_string(), path.to_string());
+
+    let mut content_length = 0;
+    loop {
+        let line = read_line()?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                content_length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error(400, "invalid Content-Length"))?;
+            }
+        }
+    }
+
+    if content_length > max_input_bytes {
+        return Err(Response::error(
+            413,
+            format!("input is larger than {} bytes", max_input_bytes),
+        ));
+    }
+    let mut body = vec![0; content_length];
+    reader
+        .read_exact(&mut body)
+        .map_err(|e| Response::error(400, format!("could not read request body: {}", e)))?;
+    Ok((method, path, body))
+}
+
+/// Routes a request. Does not touch the network, so it can be tested directly.
+pub fn handle(method: &str, path: &str, body: Vec<u8>, options: &Options) -> Response {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match (method, segments.as_slice()) {
+        ("GET", ["days"]) => Response::ok(json!({
+            "days": days::DAYS
+                .iter()
+                .map(|day| json!({ "day": day.day, "parts": [1, 2] }))
+                .collect::<Vec<_>>(),
+        })),
+        ("POST", ["solve", day, part]) => {
+            let (Ok(day), Ok(part)) = (day.parse::<u8>(), part.parse::<u8>()) else {
+                return Response::error(400, "day and part must be numbers");
+            };
+            let Some(solver) = days::get(day).and_then(|entry| entry.part(part)) else {
+                return Response::error(404, format!("day {} part {} is not solved", day, part));
+            };
+            if body.len() > options.max_input_bytes {
+                return Response::error(
+                    413,
+                    format!("input is larger than {} bytes", options.max_input_bytes),
+                );
+            }
+            let Ok(input) = String::from_utf8(body) else {
+                return Response::error(400, "input must be UTF-8");
+            };
+            solve(day, part, solver, input, options.timeout)
+        }
+        (_, ["days"]) | (_, ["solve", _, _]) => Response::error(405, "method not allowed"),
+        _ => Response::error(404, format!("no route for {}", path)),
+    }
+}
+
+/// Runs a solver on its own thread. A solver that panics on malformed input yields `422`,
+/// one that exceeds the timeout is left running in the background and yields `504`.
+fn solve(day: u8, part: u8, solver: Solver, input: String, timeout: Duration) -> Response {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // the receiver is gone if the request timed out.
+        sender.send(days::run_part(solver, &input)).ok();
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(Ok((answer, elapsed))) => Response::ok(json!({
+            "day": day,
+            "part": part,
+            "answer": answer,
+            "elapsed_ns": elapsed.as_nanos() as u64,
+        })),
+        Ok(Err(message)) => Response {
+            status: 422,
+            body: json!({
+                "day": day,
+                "part": part,
+                "error": message,
+            }),
+        },
+        Err(_) => Response::error(504, format!("no answer within {:.2?}", timeout)),
+    }
+}
+
+fn write_response(writer: &mut impl Write, response: &Response) -> io::Result<()> {
+    let body = response.body.to_string();
+    write!(
+        writer,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        body.len(),
+        body
+    )?;
+    writer.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> Options {
+        Options {
+            port: 0,
+            max_input_bytes: 1024,
+            timeout: Duration::from_secs(5),
+        }
+    }
+
+    #[test]
+    fn test_days() {
+        let response = handle("GET", "/days", vec![], &options());
+        assert_eq!(response.status, 200);
+        assert_eq!(
+            response.body["days"][0],
+            json!({ "day": 1, "parts": [1, 2] })
+        );
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = crate::read_file("examples", 1).into_bytes();
+        let response = handle("POST", "/solve/1/2", input, &options());
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body["answer"], json!(45000));
+
+        assert_eq!(
+            handle("POST", "/solve/26/1", vec![], &options()).status,
+            404
+        );
+        assert_eq!(handle("POST", "/solve/1/3", vec![], &options()).status, 404);
+        assert_eq!(handle("GET", "/solve/1/1", vec![], &options()).status, 405);
+        assert_eq!(
+            handle("POST", "/solve/1/1", vec![0xff], &options()).status,
+            400
+        );
+    }
+
+    #[test]
+    fn test_solver_errors() {
+        let panicking: Solver = |_| panic!("malformed input");
+        let response = solve(1, 1, panicking, String::new(), Duration::from_secs(5));
+        assert_eq!(response.status, 422);
+        assert_eq!(response.body["error"], json!("malformed input"));
+
+        let slow: Solver = |_| {
+            thread::sleep(Duration::from_millis(200));
+            None
+        };
+        let response = solve(1, 1, slow, String::new(), Duration::from_millis(10));
+        assert_eq!(response.status, 504);
+    }
+
+    #[test]
+    fn test_read_request() {
+        let request = b"POST /solve/1/1 HTTP/1.1\r\nContent-Length: 4\r\n\r\n1000";
+        let (method, path, body) = read_request(&mut &request[..], 1024).unwrap();
+        assert_eq!(
+            (method.as_str(), path.as_str(), body),
+            ("POST", "/solve/1/1", b"1000".to_vec())
+        );
+
+        let too_large = b"POST /solve/1/1 HTTP/1.1\r\nContent-Length: 2048\r\n\r\n";
+        assert_eq!(
+            read_request(&mut &too_large[..], 1024).unwrap_err().status,
+            413
+        );
+
+        let long_header = format!(
+            "GET /days HTTP/1.1\r\nX: {}\r\n\r\n",
+            "a".repeat(MAX_HEADER_BYTES)
+        );
+        assert_eq!(
+            read_request(&mut long_header.as_bytes(), 1024)
+                .unwrap_err()
+                .status,
+            431
+        );
+    }
+}